@@ -0,0 +1,126 @@
+//! Matches elements against many patterns at once.
+
+use crate::{delimiter_positions, find_delimiter, rfind_delimiter, StrList};
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, BuildError, Input, StartKind};
+use std::iter::FusedIterator;
+use std::str;
+
+/// An automaton built from string patterns only.
+///
+/// Since string patterns never contain the delimiter, a match found in the encoding of a list
+/// never spans two elements.
+#[derive(Clone, Debug)]
+pub struct StrPatterns {
+    inner: AhoCorasick,
+}
+
+impl StrPatterns {
+    /// Builds an automaton with the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the automaton exceeds the limits of `aho-corasick`.
+    pub fn new<I, P>(patterns: I) -> Result<Self, BuildError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        Self::with_builder(&AhoCorasickBuilder::new(), patterns)
+    }
+
+    /// Builds an automaton with the configuration of `builder`.
+    ///
+    /// The start kind of `builder` is overridden with [`StartKind::Unanchored`], since searches
+    /// through a list are never anchored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the automaton exceeds the limits of `aho-corasick`.
+    pub fn with_builder<I, P>(builder: &AhoCorasickBuilder, patterns: I) -> Result<Self, BuildError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        builder
+            .clone()
+            .start_kind(StartKind::Unanchored)
+            .build(
+                patterns
+                    .into_iter()
+                    .map(|pattern| pattern.as_ref().to_owned()),
+            )
+            .map(|inner| Self { inner })
+    }
+
+    #[must_use]
+    pub fn as_aho_corasick(&self) -> &AhoCorasick {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn into_aho_corasick(self) -> AhoCorasick {
+        self.inner
+    }
+}
+
+impl StrList {
+    /// Builds an automaton that searches for the elements of the list as patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the automaton exceeds the limits of `aho-corasick`.
+    pub fn to_aho_corasick(&self) -> Result<StrPatterns, BuildError> {
+        StrPatterns::new(self)
+    }
+
+    /// Returns an iterator over the elements that contain a match of `patterns`, together with
+    /// their indices.
+    ///
+    /// The whole list is searched in a single pass.
+    #[must_use]
+    pub fn filter_containing_any<'a>(&'a self, patterns: &'a StrPatterns) -> ContainingAny<'a> {
+        ContainingAny {
+            list: self,
+            patterns: &patterns.inner,
+            position: 0,
+            index: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ContainingAny<'a> {
+    list: &'a StrList,
+    patterns: &'a AhoCorasick,
+    position: usize,
+    index: usize,
+}
+
+impl<'a> Iterator for ContainingAny<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = &self.list.inner;
+        let input = Input::new(data).range(self.position..);
+        let start = self.patterns.find(input)?.start();
+
+        if start == data.len() {
+            self.position = start;
+
+            return None;
+        }
+
+        let element_start = rfind_delimiter(&data[self.position..start])
+            .map_or(self.position, |i| self.position + i + 1);
+        let element_end = start + find_delimiter(&data[start..])?;
+        let index = self.index + delimiter_positions(&data[self.position..element_start]).count();
+        let element = unsafe { str::from_utf8_unchecked(&data[element_start..element_end]) };
+
+        self.position = element_end + 1;
+        self.index = index + 1;
+
+        Some((index, element))
+    }
+}
+
+impl FusedIterator for ContainingAny<'_> {}
@@ -0,0 +1,120 @@
+//! A growable list that stores its buffer in a custom allocator, using the `allocator-api2`
+//! polyfill of the unstable `Allocator` trait.
+
+use crate::{
+    impl_str_list_storage_mut_traits, impl_str_list_storage_traits, impl_str_list_vec_methods,
+    StrList,
+};
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::vec::Vec;
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+/// Same as [`StrListBuf`](crate::StrListBuf), but backed by a `Vec<u8, A>`.
+///
+/// `StrListBuf` itself can't take an allocator parameter while `Allocator` is unstable, so this is
+/// a separate type. Only appending and truncating are available on the buffer itself. Elements can
+/// still be edited in place through [`StrList`], and [`StrList::to_str_list_buf`] copies the list
+/// into a `StrListBuf`.
+#[derive(Clone)]
+pub struct StrListBufIn<A: Allocator = Global> {
+    inner: Vec<u8, A>,
+}
+
+impl<A: Allocator> StrListBufIn<A> {
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            inner: Vec::new_in(alloc),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            inner: Vec::with_capacity_in(capacity, alloc),
+        }
+    }
+
+    /// Copies the elements of `list` into a new list using `alloc`.
+    #[must_use]
+    pub fn from_str_list_in(list: &StrList, alloc: A) -> Self {
+        let mut result = Self::with_capacity_in(list.inner.len(), alloc);
+
+        result.extend_from_list(list);
+
+        result
+    }
+
+    /// Creates a `StrListBufIn` from its underlying encoding without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be a valid encoding as described in [`StrList::as_bytes`].
+    #[must_use]
+    pub unsafe fn from_vec_unchecked(data: Vec<u8, A>) -> Self {
+        Self { inner: data }
+    }
+
+    /// Converts the list into its underlying encoding, as described in [`StrList::as_bytes`].
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8, A> {
+        self.inner
+    }
+
+    #[must_use]
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
+
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        self
+    }
+}
+
+impl_str_list_vec_methods!([A: Allocator] StrListBufIn<A>);
+impl_str_list_storage_traits!([A: Allocator] StrListBufIn<A>);
+impl_str_list_storage_mut_traits!([A: Allocator] StrListBufIn<A>);
+
+impl<A: Allocator + Default> Default for StrListBufIn<A> {
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<A: Allocator> Deref for StrListBufIn<A> {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { StrList::from_bytes_unchecked(&self.inner) }
+    }
+}
+
+impl<A: Allocator> DerefMut for StrListBufIn<A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { StrList::from_bytes_unchecked_mut(&mut self.inner) }
+    }
+}
+
+impl<A: Allocator + Default> From<&StrList> for StrListBufIn<A> {
+    fn from(value: &StrList) -> Self {
+        Self::from_str_list_in(value, A::default())
+    }
+}
+
+impl<A: Allocator + Default, S: AsRef<str>> FromIterator<S> for StrListBufIn<A> {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut result = Self::default();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl<A: Allocator, B: Allocator> PartialEq<StrListBufIn<B>> for StrListBufIn<A> {
+    fn eq(&self, other: &StrListBufIn<B>) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
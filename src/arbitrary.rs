@@ -0,0 +1,42 @@
+//! Generates lists from fuzzer input.
+
+use crate::{StrList, StrListBuf};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for StrListBuf {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter::<&str>()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter::<&str>()?.collect()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Box<StrList> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        StrListBuf::arbitrary(u).map(StrListBuf::into_boxed_str_list)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        StrListBuf::arbitrary_take_rest(u).map(StrListBuf::into_boxed_str_list)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        StrListBuf::size_hint(depth)
+    }
+}
+
+/// Generates a valid encoding as described in [`StrList::as_bytes`], for fuzzing code that consumes
+/// raw bytes, such as [`StrList::from_bytes_unchecked`].
+///
+/// # Errors
+///
+/// Returns an error if `u` does not contain enough data.
+pub fn arbitrary_encoding(u: &mut Unstructured) -> Result<Vec<u8>> {
+    StrListBuf::arbitrary(u).map(StrListBuf::into_bytes)
+}
@@ -0,0 +1,65 @@
+//! Serializes lists in the same format as `Vec<String>`.
+//!
+//! The encoding is deterministic: a little-endian `u32` element count, followed by each element as
+//! a little-endian `u32` byte length and its UTF-8 bytes. Equal lists always produce identical
+//! bytes, and deserialization rejects any input that is not exactly such an encoding.
+
+use crate::{StrList, StrListBuf, DELIMITER};
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::convert::TryFrom;
+use std::str;
+
+fn write_length<W: Write>(length: usize, writer: &mut W) -> Result<()> {
+    u32::try_from(length)
+        .map_err(|_| Error::from(ErrorKind::InvalidData))?
+        .serialize(writer)
+}
+
+impl BorshSerialize for StrList {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_length(self.len(), writer)?;
+
+        for element in self {
+            write_length(element.len(), writer)?;
+            writer.write_all(element.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BorshSerialize for StrListBuf {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_str_list().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for StrListBuf {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let count = u32::deserialize_reader(reader)?;
+        let mut result = Self::new();
+
+        for _ in 0..count {
+            let length = u32::deserialize_reader(reader)?;
+            let start = result.inner.len();
+
+            if reader
+                .by_ref()
+                .take(u64::from(length))
+                .read_to_end(&mut result.inner)? as u64
+                != u64::from(length)
+            {
+                return Err(ErrorKind::UnexpectedEof.into());
+            }
+
+            if str::from_utf8(&result.inner[start..]).is_err() {
+                return Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8"));
+            }
+
+            result.inner.push(DELIMITER);
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,78 @@
+//! Filters elements by shell-style patterns such as `foo/*`, `file?.txt` or `[a-c]*`.
+//!
+//! As in a shell, wildcards never match a `/` separator, so `foo/*` does not match `foo/a/b`.
+
+use crate::{Iter, StrList};
+use glob::{MatchOptions, Pattern, PatternError};
+use std::iter::FusedIterator;
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+impl StrList {
+    /// Returns an iterator over the elements that match the glob `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid glob pattern.
+    pub fn iter_glob(&self, pattern: &str) -> Result<IterGlob<'_>, PatternError> {
+        Ok(self.iter_pattern(Pattern::new(pattern)?))
+    }
+
+    /// Returns an iterator over the elements that do not match the glob `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid glob pattern.
+    pub fn iter_glob_excluding(&self, pattern: &str) -> Result<IterGlob<'_>, PatternError> {
+        Ok(self.iter_pattern_excluding(Pattern::new(pattern)?))
+    }
+
+    /// Same as [`StrList::iter_glob`], but with an already compiled `pattern`.
+    #[must_use]
+    pub fn iter_pattern(&self, pattern: Pattern) -> IterGlob<'_> {
+        IterGlob {
+            inner: self.iter(),
+            pattern,
+            include: true,
+        }
+    }
+
+    /// Same as [`StrList::iter_glob_excluding`], but with an already compiled `pattern`.
+    #[must_use]
+    pub fn iter_pattern_excluding(&self, pattern: Pattern) -> IterGlob<'_> {
+        IterGlob {
+            inner: self.iter(),
+            pattern,
+            include: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IterGlob<'a> {
+    inner: Iter<'a>,
+    pattern: Pattern,
+    include: bool,
+}
+
+impl<'a> Iterator for IterGlob<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pattern = &self.pattern;
+        let include = self.include;
+
+        self.inner
+            .find(|element| pattern.matches_with(element, MATCH_OPTIONS) == include)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl FusedIterator for IterGlob<'_> {}
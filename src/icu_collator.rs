@@ -0,0 +1,12 @@
+//! Sorts elements with locale-aware ICU collation.
+
+use crate::StrList;
+use icu_collator::CollatorBorrowed;
+
+impl StrList {
+    /// Sorts the list with the locale-aware ordering of `collator`, preserving the order of equal
+    /// elements.
+    pub fn sort_by_collator(&mut self, collator: &CollatorBorrowed) {
+        self.sort_by(|lhs, rhs| collator.compare(lhs, rhs));
+    }
+}
@@ -2,54 +2,500 @@
 
 use std::borrow::{Borrow, BorrowMut};
 use std::cmp::Ordering;
-use std::fmt::{self, Debug, Formatter};
-use std::iter::FromIterator;
-use std::ops::{Deref, DerefMut};
-use std::{mem, str};
+use std::collections::{HashSet, TryReserveError};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ffi::{OsStr, OsString};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::{
+    Add, AddAssign, Bound, Deref, DerefMut, Index, IndexMut, Mul, Range, RangeBounds, RangeFrom,
+    RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::slice::{self, GetDisjointMutError};
+use std::sync::Arc;
+use std::{io, iter, mem, ptr, str, vec};
+
+#[cfg(feature = "aho-corasick")]
+pub mod aho_corasick;
+#[cfg(feature = "allocator-api2")]
+pub mod allocator_api2;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "borsh")]
+pub mod borsh;
+#[cfg(feature = "glob")]
+pub mod glob;
+#[cfg(feature = "icu_collator")]
+pub mod icu_collator;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+pub mod serde_bytes;
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
 
 const DELIMITER: u8 = 0xff;
+const GROUP_DELIMITER: u8 = 0xfe;
+
+#[cfg(feature = "memchr")]
+fn find_delimiter(data: &[u8]) -> Option<usize> {
+    memchr::memchr(DELIMITER, data)
+}
+
+#[cfg(not(feature = "memchr"))]
+fn find_delimiter(data: &[u8]) -> Option<usize> {
+    data.iter().position(|&b| b == DELIMITER)
+}
+
+#[cfg(feature = "memchr")]
+fn rfind_delimiter(data: &[u8]) -> Option<usize> {
+    memchr::memrchr(DELIMITER, data)
+}
+
+#[cfg(not(feature = "memchr"))]
+fn rfind_delimiter(data: &[u8]) -> Option<usize> {
+    data.iter().rposition(|&b| b == DELIMITER)
+}
+
+#[cfg(feature = "memchr")]
+fn delimiter_positions(data: &[u8]) -> impl DoubleEndedIterator<Item = usize> + '_ {
+    memchr::memchr_iter(DELIMITER, data)
+}
+
+#[cfg(not(feature = "memchr"))]
+fn delimiter_positions(data: &[u8]) -> impl DoubleEndedIterator<Item = usize> + '_ {
+    data.iter()
+        .enumerate()
+        .filter_map(|(i, &b)| if b == DELIMITER { Some(i) } else { None })
+}
+
+#[cfg(feature = "memchr")]
+fn find_all<'a>(haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    memchr::memmem::find_iter(haystack, needle)
+}
+
+#[cfg(not(feature = "memchr"))]
+fn find_all<'a>(haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    haystack
+        .windows(needle.len())
+        .enumerate()
+        .filter_map(move |(i, window)| if window == needle { Some(i) } else { None })
+}
+
+#[cfg(feature = "memchr")]
+fn rfind_all<'a>(haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    memchr::memmem::rfind_iter(haystack, needle)
+}
+
+#[cfg(not(feature = "memchr"))]
+fn rfind_all<'a>(haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    haystack
+        .windows(needle.len())
+        .enumerate()
+        .rev()
+        .filter_map(move |(i, window)| if window == needle { Some(i) } else { None })
+}
+
+unsafe fn span_str<'a>(data: &'a [u8], span: &Range<usize>) -> &'a str {
+    str::from_utf8_unchecked(data.get_unchecked(span.clone()))
+}
+
+fn natural_cmp(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    fn split_digits(data: &[u8]) -> (&[u8], &[u8]) {
+        let length = data.iter().take_while(|b| b.is_ascii_digit()).count();
+
+        data.split_at(length)
+    }
+
+    let (mut lhs_rest, mut rhs_rest) = (lhs, rhs);
+
+    loop {
+        match (lhs_rest.first(), rhs_rest.first()) {
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let (lhs_digits, lhs_tail) = split_digits(lhs_rest);
+                let (rhs_digits, rhs_tail) = split_digits(rhs_rest);
+                let lhs_number =
+                    &lhs_digits[lhs_digits.iter().take_while(|&&b| b == b'0').count()..];
+                let rhs_number =
+                    &rhs_digits[rhs_digits.iter().take_while(|&&b| b == b'0').count()..];
+                let ordering = lhs_number
+                    .len()
+                    .cmp(&rhs_number.len())
+                    .then_with(|| lhs_number.cmp(rhs_number));
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                lhs_rest = lhs_tail;
+                rhs_rest = rhs_tail;
+            }
+            (Some(l), Some(r)) => {
+                if l != r {
+                    return l.cmp(r);
+                }
+
+                lhs_rest = &lhs_rest[1..];
+                rhs_rest = &rhs_rest[1..];
+            }
+            (l, r) => return l.cmp(&r).then_with(|| lhs.cmp(rhs)),
+        }
+    }
+}
 
 #[derive(Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::Unaligned)
+)]
 #[repr(transparent)]
 pub struct StrList {
     inner: [u8],
 }
 
 impl StrList {
-    unsafe fn from_bytes_unchecked(data: &[u8]) -> &Self {
-        &*(data as *const _ as *const _)
+    /// Reinterprets `data` as a `StrList` without checking the encoding.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be a valid encoding as described in [`StrList::as_bytes`]: every element must
+    /// be valid UTF-8 and `data` must be either empty or end with a delimiter. Use
+    /// [`StrList::from_bytes`] for a checked version.
+    #[must_use]
+    pub const unsafe fn from_bytes_unchecked(data: &[u8]) -> &Self {
+        &*(ptr::from_ref(data) as *const Self)
+    }
+
+    /// Mutable version of [`StrList::from_bytes_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// Same as [`StrList::from_bytes_unchecked`].
+    #[must_use]
+    pub const unsafe fn from_bytes_unchecked_mut(data: &mut [u8]) -> &mut Self {
+        &mut *(ptr::from_mut(data) as *mut Self)
+    }
+
+    fn element_start(&self, index: usize) -> Option<usize> {
+        if index == 0 {
+            Some(0)
+        } else {
+            delimiter_positions(&self.inner)
+                .nth(index - 1)
+                .map(|i| i + 1)
+        }
+    }
+
+    fn element_start_back(&self, index: usize) -> Option<usize> {
+        if index == 0 {
+            Some(self.inner.len())
+        } else {
+            let mut positions = delimiter_positions(&self.inner).rev();
+
+            positions.nth(index - 1)?;
+
+            Some(positions.next().map_or(0, |i| i + 1))
+        }
+    }
+
+    fn byte_range<R: RangeBounds<usize>>(&self, range: R) -> Option<Range<usize>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+
+        let byte_start = self.element_start(start)?;
+        let rest = unsafe { Self::from_bytes_unchecked(self.inner.get_unchecked(byte_start..)) };
+
+        let byte_length = match range.end_bound() {
+            Bound::Included(&end) => rest.element_start(end.checked_add(1)?.checked_sub(start)?)?,
+            Bound::Excluded(&end) => rest.element_start(end.checked_sub(start)?)?,
+            Bound::Unbounded => rest.inner.len(),
+        };
+
+        Some(byte_start..byte_start + byte_length)
+    }
+
+    fn element_range(&self, index: usize) -> Option<Range<usize>> {
+        let start = self.element_start(index)?;
+        let length = find_delimiter(unsafe { self.inner.get_unchecked(start..) })?;
+
+        Some(start..start + length)
+    }
+
+    /// Reinterprets `data` as a `StrList`, checking that every element is valid UTF-8 and that
+    /// `data` is either empty or ends with a delimiter.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] with the first offending byte offset if `data` is not a valid
+    /// encoding.
+    pub const fn from_bytes(data: &[u8]) -> Result<&Self, FromBytesError> {
+        let mut start = 0;
+        let mut end = 0;
+
+        while end <= data.len() {
+            if end == data.len() || data[end] == DELIMITER {
+                let element = data.split_at(end).0.split_at(start).1;
+
+                if let Err(error) = str::from_utf8(element) {
+                    return Err(FromBytesError::InvalidUtf8 {
+                        offset: start + error.valid_up_to(),
+                    });
+                }
+
+                start = end + 1;
+            }
+
+            end += 1;
+        }
+
+        match data.last() {
+            None | Some(&DELIMITER) => Ok(unsafe { Self::from_bytes_unchecked(data) }),
+            Some(_) => Err(FromBytesError::MissingDelimiter { offset: data.len() }),
+        }
+    }
+
+    /// Mutable version of [`StrList::from_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`StrList::from_bytes`].
+    pub const fn from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, FromBytesError> {
+        match Self::from_bytes(data) {
+            Ok(_) => Ok(unsafe { Self::from_bytes_unchecked_mut(data) }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns the underlying encoding of the list.
+    ///
+    /// Each element is stored as its UTF-8 bytes followed by a single `0xff` delimiter byte, so the
+    /// result is either empty or ends with `0xff`. This encoding is a stable guarantee of the
+    /// crate.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// This counts the delimiters, so it runs in O(n) time over the underlying bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        delimiter_positions(&self.inner).count()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.element_range(index)
+            .map(|range| unsafe { str::from_utf8_unchecked(self.inner.get_unchecked(range)) })
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut str> {
+        self.element_range(index).map(move |range| unsafe {
+            str::from_utf8_unchecked_mut(self.inner.get_unchecked_mut(range))
+        })
+    }
+
+    /// Returns mutable references to several elements at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any index is out of bounds or if the same index appears more than once.
+    pub fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut str; N], GetDisjointMutError> {
+        for (i, index) in indices.iter().enumerate() {
+            if indices[..i].contains(index) {
+                return Err(GetDisjointMutError::OverlappingIndices);
+            }
+        }
+
+        let ranges = indices.map(|index| self.element_range(index));
+
+        if ranges.iter().any(Option::is_none) {
+            return Err(GetDisjointMutError::IndexOutOfBounds);
+        }
+
+        let data = self.inner.as_mut_ptr();
+
+        Ok(ranges.map(|range| unsafe {
+            let range = range.unwrap_unchecked();
+
+            str::from_utf8_unchecked_mut(slice::from_raw_parts_mut(
+                data.add(range.start),
+                range.len(),
+            ))
+        }))
+    }
+
+    /// Returns the elements in `range` as a sub-list, or `None` if `range` is out of bounds.
+    #[must_use]
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<&Self> {
+        self.byte_range(range)
+            .map(|range| unsafe { Self::from_bytes_unchecked(self.inner.get_unchecked(range)) })
     }
 
-    unsafe fn from_bytes_unchecked_mut(data: &mut [u8]) -> &mut Self {
-        &mut *(data as *mut _ as *mut _)
+    /// Mutable version of [`StrList::get_range`].
+    #[must_use]
+    pub fn get_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Option<&mut Self> {
+        self.byte_range(range).map(move |range| unsafe {
+            Self::from_bytes_unchecked_mut(self.inner.get_unchecked_mut(range))
+        })
     }
 
     #[must_use]
-    pub fn iter(&self) -> Iter {
+    pub fn iter(&self) -> Iter<'_> {
         Iter { inner: self }
     }
 
+    /// Returns an iterator that counts the elements up front so that it can report its exact
+    /// length.
+    #[must_use]
+    pub fn iter_counted(&self) -> CountedIter<'_> {
+        CountedIter {
+            inner: self.iter(),
+            remaining: self.len(),
+        }
+    }
+
     #[must_use]
-    pub fn iter_mut(&mut self) -> IterMut {
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
         IterMut { inner: self }
     }
 
+    /// Divides the list into two at an element index, returning the elements `..mid` and `mid..`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the number of elements.
+    #[must_use]
+    pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        let position = self.element_start(mid).expect("split index out of bounds");
+        let (left, right) = self.inner.split_at(position);
+
+        unsafe {
+            (
+                Self::from_bytes_unchecked(left),
+                Self::from_bytes_unchecked(right),
+            )
+        }
+    }
+
+    /// Mutable version of [`StrList::split_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the number of elements.
+    #[must_use]
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
+        let position = self.element_start(mid).expect("split index out of bounds");
+        let (left, right) = self.inner.split_at_mut(position);
+
+        unsafe {
+            (
+                Self::from_bytes_unchecked_mut(left),
+                Self::from_bytes_unchecked_mut(right),
+            )
+        }
+    }
+
+    fn prefix_length<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&str) -> bool,
+    {
+        self.iter()
+            .take_while(|element| predicate(element))
+            .map(|element| element.len() + 1)
+            .sum()
+    }
+
+    /// Returns the longest prefix of the list whose elements all satisfy `predicate`.
+    pub fn take_while_list<P>(&self, predicate: P) -> &Self
+    where
+        P: FnMut(&str) -> bool,
+    {
+        let length = self.prefix_length(predicate);
+
+        unsafe { Self::from_bytes_unchecked(self.inner.get_unchecked(..length)) }
+    }
+
+    /// Returns the rest of the list after the longest prefix whose elements all satisfy
+    /// `predicate`.
+    pub fn skip_while_list<P>(&self, predicate: P) -> &Self
+    where
+        P: FnMut(&str) -> bool,
+    {
+        let length = self.prefix_length(predicate);
+
+        unsafe { Self::from_bytes_unchecked(self.inner.get_unchecked(length..)) }
+    }
+
+    /// Returns an iterator over sub-lists of `chunk_size` elements. The last chunk has fewer
+    /// elements if the number of elements is not divisible by `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    #[must_use]
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_> {
+        assert_ne!(chunk_size, 0, "chunk size must be non-zero");
+
+        Chunks {
+            rest: self,
+            chunk_size,
+        }
+    }
+
+    /// Returns an iterator over all contiguous sub-lists of `size` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn windows(&self, size: usize) -> Windows<'_> {
+        assert_ne!(size, 0, "window size must be non-zero");
+
+        Windows {
+            rest: self,
+            end: self.element_start(size),
+        }
+    }
+
     #[must_use]
     pub fn split_first(&self) -> Option<(&str, &Self)> {
-        self.inner
-            .iter()
-            .position(|&b| b == DELIMITER)
-            .map(|i| unsafe {
-                (
-                    str::from_utf8_unchecked(self.inner.get_unchecked(..i)),
-                    Self::from_bytes_unchecked(self.inner.get_unchecked(i + 1..)),
-                )
-            })
+        find_delimiter(&self.inner).map(|i| unsafe {
+            (
+                str::from_utf8_unchecked(self.inner.get_unchecked(..i)),
+                Self::from_bytes_unchecked(self.inner.get_unchecked(i + 1..)),
+            )
+        })
     }
 
     #[must_use]
     pub fn split_first_mut(&mut self) -> Option<(&mut str, &mut Self)> {
-        let delimiter_position = self.inner.iter().position(|&b| b == DELIMITER);
+        let delimiter_position = find_delimiter(&self.inner);
 
         delimiter_position.map(move |i| {
             let (left, right) = self.inner.split_at_mut(i);
@@ -66,10 +512,7 @@ impl StrList {
     #[must_use]
     pub fn split_last(&self) -> Option<(&str, &Self)> {
         self.inner.split_last().map(|(_, inner)| {
-            let i = inner
-                .iter()
-                .rposition(|&b| b == DELIMITER)
-                .map_or(0, |i| i + 1);
+            let i = rfind_delimiter(inner).map_or(0, |i| i + 1);
 
             unsafe {
                 (
@@ -80,304 +523,4912 @@ impl StrList {
         })
     }
 
-    #[must_use]
-    pub fn split_last_mut(&mut self) -> Option<(&mut str, &mut Self)> {
-        self.inner.split_last_mut().map(|(_, inner)| {
-            let i = inner
-                .iter()
-                .rposition(|&b| b == DELIMITER)
-                .map_or(0, |i| i + 1);
+    #[must_use]
+    pub fn split_last_mut(&mut self) -> Option<(&mut str, &mut Self)> {
+        self.inner.split_last_mut().map(|(_, inner)| {
+            let i = rfind_delimiter(inner).map_or(0, |i| i + 1);
+
+            // TODO: Use `[T]::split_at_unchecked_mut`: https://github.com/rust-lang/rust/issues/76014.
+
+            let (left, right) = inner.split_at_mut(i);
+
+            unsafe {
+                (
+                    str::from_utf8_unchecked_mut(right),
+                    Self::from_bytes_unchecked_mut(left),
+                )
+            }
+        })
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<&str> {
+        self.split_first().map(|(first, _)| first)
+    }
+
+    #[must_use]
+    pub fn first_mut(&mut self) -> Option<&mut str> {
+        self.split_first_mut().map(|(first, _)| first)
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<&str> {
+        self.split_last().map(|(last, _)| last)
+    }
+
+    #[must_use]
+    pub fn last_mut(&mut self) -> Option<&mut str> {
+        self.split_last_mut().map(|(last, _)| last)
+    }
+
+    /// Swaps the elements at positions `a` and `b`. Elements of different lengths are swapped by
+    /// rotating the bytes between them, so the total length of the list is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        let first = self.element_range(a).expect("swap index out of bounds");
+        let second = self.element_range(b).expect("swap index out of bounds");
+
+        if a != b {
+            let (left, right) = self.inner.split_at_mut(second.start);
+
+            if first.len() == second.len() {
+                left[first.clone()].swap_with_slice(&mut right[..second.len()]);
+            } else {
+                let region = &mut self.inner[first.start..=second.end];
+
+                region.rotate_left(first.len() + 1);
+                region[..second.end - first.end].rotate_right(second.len() + 1);
+            }
+        }
+    }
+
+    /// Rotates the list in place such that the first `mid` elements move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the number of elements.
+    pub fn rotate_left(&mut self, mid: usize) {
+        let position = self
+            .element_start(mid)
+            .expect("rotation amount out of bounds");
+
+        self.inner.rotate_left(position);
+    }
+
+    /// Rotates the list in place such that the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the number of elements.
+    pub fn rotate_right(&mut self, k: usize) {
+        let position = self
+            .element_start_back(k)
+            .expect("rotation amount out of bounds");
+
+        self.inner.rotate_left(position);
+    }
+
+    fn element_spans(&self) -> Vec<Range<usize>> {
+        let mut start = 0;
+
+        delimiter_positions(&self.inner)
+            .map(|end| {
+                let span = start..end;
+
+                start = end + 1;
+
+                span
+            })
+            .collect()
+    }
+
+    /// Reorders the elements by letting `f` permute their spans, then writes the result in a
+    /// single pass, so each byte is moved exactly once no matter how the elements are shuffled.
+    fn permute<F>(&mut self, f: F)
+    where
+        F: FnOnce(&[u8], &mut [Range<usize>]),
+    {
+        let mut spans = self.element_spans();
+
+        f(&self.inner, &mut spans);
+
+        let mut result = Vec::with_capacity(self.inner.len());
+
+        for span in spans {
+            result.extend_from_slice(&self.inner[span.start..=span.end]);
+        }
+
+        self.inner.copy_from_slice(&result);
+    }
+
+    /// Sorts the elements in lexicographic order. This sort is stable.
+    pub fn sort(&mut self) {
+        self.permute(|data, spans| {
+            spans.sort_by(|lhs, rhs| data[lhs.clone()].cmp(&data[rhs.clone()]));
+        });
+    }
+
+    /// Sorts the elements in lexicographic order. This sort is unstable, which only matters for
+    /// performance since equal elements are indistinguishable.
+    pub fn sort_unstable(&mut self) {
+        self.permute(|data, spans| {
+            spans.sort_unstable_by(|lhs, rhs| data[lhs.clone()].cmp(&data[rhs.clone()]));
+        });
+    }
+
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&str, &str) -> Ordering,
+    {
+        self.permute(|data, spans| {
+            spans.sort_by(|lhs, rhs| unsafe { compare(span_str(data, lhs), span_str(data, rhs)) });
+        });
+    }
+
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> K,
+        K: Ord,
+    {
+        self.permute(|data, spans| spans.sort_by_key(|span| f(unsafe { span_str(data, span) })));
+    }
+
+    /// Same as [`StrList::sort_by_key`], but calls `f` only once per element.
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> K,
+        K: Ord,
+    {
+        self.permute(|data, spans| {
+            spans.sort_by_cached_key(|span| f(unsafe { span_str(data, span) }));
+        });
+    }
+
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&str, &str) -> Ordering,
+    {
+        self.permute(|data, spans| {
+            spans.sort_unstable_by(|lhs, rhs| unsafe {
+                compare(span_str(data, lhs), span_str(data, rhs))
+            });
+        });
+    }
+
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> K,
+        K: Ord,
+    {
+        self.permute(|data, spans| {
+            spans.sort_unstable_by_key(|span| f(unsafe { span_str(data, span) }));
+        });
+    }
+
+    /// Sorts the list, ignoring ASCII case, without preserving the order of equal elements.
+    pub fn sort_unstable_by_ignore_ascii_case(&mut self) {
+        self.permute(|data, spans| {
+            spans.sort_unstable_by(|lhs, rhs| {
+                let lhs = data[lhs.clone()].iter().map(u8::to_ascii_lowercase);
+                let rhs = data[rhs.clone()].iter().map(u8::to_ascii_lowercase);
+
+                lhs.cmp(rhs)
+            });
+        });
+    }
+
+    /// Sorts the list in natural order, comparing runs of ASCII digits by their numeric value, so
+    /// that `"file2"` comes before `"file10"`. The order of equal elements is preserved.
+    pub fn sort_natural(&mut self) {
+        self.permute(|data, spans| {
+            spans.sort_by(|lhs, rhs| natural_cmp(&data[lhs.clone()], &data[rhs.clone()]));
+        });
+    }
+
+    /// Reverses the order of the elements in place, without allocating.
+    pub fn reverse(&mut self) {
+        if let Some((_, content)) = self.inner.split_last_mut() {
+            content.reverse();
+
+            for element in content.split_mut(|&b| b == DELIMITER) {
+                element.reverse();
+            }
+        }
+    }
+
+    fn element_needle(x: &str) -> Vec<u8> {
+        let mut needle = Vec::with_capacity(x.len() + 1);
+
+        needle.extend_from_slice(x.as_bytes());
+        needle.push(DELIMITER);
+
+        needle
+    }
+
+    fn is_element_start(&self, position: usize) -> bool {
+        position == 0 || self.inner[position - 1] == DELIMITER
+    }
+
+    /// Returns `true` if the list contains an element equal to `x`.
+    #[must_use]
+    pub fn contains(&self, x: &str) -> bool {
+        self.position(x).is_some()
+    }
+
+    /// Returns the index of the first element equal to `x`.
+    ///
+    /// Since elements are delimited, this is a substring search for `x` followed by a delimiter
+    /// over the underlying bytes, rather than an element-by-element comparison.
+    #[must_use]
+    pub fn position(&self, x: &str) -> Option<usize> {
+        let needle = Self::element_needle(x);
+
+        let start = find_all(&self.inner, &needle).find(|&i| self.is_element_start(i))?;
+
+        Some(delimiter_positions(&self.inner[..start]).count())
+    }
+
+    /// Returns the index of the last element equal to `x`. See [`StrList::position`] for details.
+    #[must_use]
+    pub fn rposition(&self, x: &str) -> Option<usize> {
+        let needle = Self::element_needle(x);
+
+        let start = rfind_all(&self.inner, &needle).find(|&i| self.is_element_start(i))?;
+
+        Some(delimiter_positions(&self.inner[..start]).count())
+    }
+
+    /// Returns an iterator over the indices of the elements for which `predicate` returns `true`.
+    #[must_use]
+    pub fn find_matching<F>(&self, predicate: F) -> FindMatching<'_, F>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        FindMatching {
+            inner: self.iter().enumerate(),
+            predicate,
+        }
+    }
+
+    /// Returns an iterator over the indices of the elements that contain `needle`.
+    #[must_use]
+    pub fn find_containing<'a>(
+        &'a self,
+        needle: &'a str,
+    ) -> FindMatching<'a, impl FnMut(&str) -> bool + 'a> {
+        self.find_matching(move |element| element.contains(needle))
+    }
+
+    /// Returns `true` if the first elements of `self` are the elements of `needle`.
+    ///
+    /// Because the encoding is canonical, this is a single prefix comparison of the underlying
+    /// bytes.
+    #[must_use]
+    pub fn starts_with(&self, needle: &Self) -> bool {
+        self.inner.starts_with(&needle.inner)
+    }
+
+    /// Returns `true` if the last elements of `self` are the elements of `needle`.
+    #[must_use]
+    pub fn ends_with(&self, needle: &Self) -> bool {
+        self.inner.ends_with(&needle.inner)
+            && self.is_element_start(self.inner.len() - needle.inner.len())
+    }
+
+    /// Returns the elements after `prefix` if the list starts with `prefix`.
+    #[must_use]
+    pub fn strip_prefix(&self, prefix: &Self) -> Option<&Self> {
+        if self.starts_with(prefix) {
+            Some(unsafe {
+                Self::from_bytes_unchecked(self.inner.get_unchecked(prefix.inner.len()..))
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the elements before `suffix` if the list ends with `suffix`.
+    #[must_use]
+    pub fn strip_suffix(&self, suffix: &Self) -> Option<&Self> {
+        if self.ends_with(suffix) {
+            let length = self.inner.len() - suffix.inner.len();
+
+            Some(unsafe { Self::from_bytes_unchecked(self.inner.get_unchecked(..length)) })
+        } else {
+            None
+        }
+    }
+
+    /// Binary searches a sorted list for `x`. See [`StrList::binary_search_by`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the index where `x` could be inserted while maintaining sorted order if
+    /// `x` is not found.
+    pub fn binary_search(&self, x: &str) -> Result<usize, usize> {
+        self.binary_search_by(|element| element.cmp(x))
+    }
+
+    /// Binary searches a sorted list with a comparator function, mirroring
+    /// [`slice::binary_search_by`].
+    ///
+    /// The search bisects the underlying bytes, so it probes O(log n) elements, followed by a
+    /// single delimiter count to turn the resulting byte offset into an element index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the index where a matching element could be inserted while maintaining
+    /// sorted order if no element matches.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&str) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.inner.len();
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let start = rfind_delimiter(&self.inner[low..middle]).map_or(low, |i| low + i + 1);
+            let end = start + find_delimiter(&self.inner[start..high]).unwrap_or(high - start);
+
+            match f(unsafe { span_str(&self.inner, &(start..end)) }) {
+                Ordering::Less => low = end + 1,
+                Ordering::Equal => return Ok(delimiter_positions(&self.inner[..start]).count()),
+                Ordering::Greater => high = start,
+            }
+        }
+
+        Err(delimiter_positions(&self.inner[..low]).count())
+    }
+
+    /// Binary searches a sorted list with a key extraction function. See
+    /// [`StrList::binary_search_by`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the index where an element with key `b` could be inserted while
+    /// maintaining sorted order if no element has that key.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&str) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|element| f(element).cmp(b))
+    }
+
+    fn merge_by_order(
+        &self,
+        other: &Self,
+        left_only: bool,
+        right_only: bool,
+        both: usize,
+    ) -> StrListBuf {
+        let mut result = StrListBuf::new();
+        let mut lhs = self;
+        let mut rhs = other;
+
+        while let (Some((l, l_rest)), Some((r, r_rest))) = (lhs.split_first(), rhs.split_first()) {
+            match l.cmp(r) {
+                Ordering::Less => {
+                    if left_only {
+                        result.push(l);
+                    }
+
+                    lhs = l_rest;
+                }
+                Ordering::Equal => {
+                    for _ in 0..both {
+                        result.push(l);
+                    }
+
+                    lhs = l_rest;
+                    rhs = r_rest;
+                }
+                Ordering::Greater => {
+                    if right_only {
+                        result.push(r);
+                    }
+
+                    rhs = r_rest;
+                }
+            }
+        }
+
+        if left_only {
+            result.extend_from_list(lhs);
+        }
+
+        if right_only {
+            result.extend_from_list(rhs);
+        }
+
+        result
+    }
+
+    /// Merges two sorted lists into a sorted list containing the elements of both, in linear time.
+    #[must_use]
+    pub fn merge_sorted(&self, other: &Self) -> StrListBuf {
+        self.merge_by_order(other, true, true, 2)
+    }
+
+    /// Returns the elements in `self` or `other`, which must both be sorted and deduplicated, in
+    /// linear time.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> StrListBuf {
+        self.merge_by_order(other, true, true, 1)
+    }
+
+    /// Returns the elements in both `self` and `other`, which must both be sorted and
+    /// deduplicated, in linear time.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> StrListBuf {
+        self.merge_by_order(other, false, false, 1)
+    }
+
+    /// Returns the elements in `self` but not in `other`, which must both be sorted and
+    /// deduplicated, in linear time.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> StrListBuf {
+        self.merge_by_order(other, true, false, 0)
+    }
+
+    /// Returns the elements in exactly one of `self` and `other`, which must both be sorted and
+    /// deduplicated, in linear time.
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &Self) -> StrListBuf {
+        self.merge_by_order(other, true, true, 0)
+    }
+
+    /// Returns a new list containing the first occurrence of each distinct element, in order.
+    #[must_use]
+    pub fn unique(&self) -> StrListBuf {
+        let mut seen = HashSet::new();
+
+        self.iter()
+            .filter(|element| seen.insert(*element))
+            .collect()
+    }
+
+    /// Converts every element to ASCII lower case in place.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.inner.make_ascii_lowercase();
+    }
+
+    /// Converts every element to ASCII upper case in place.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.inner.make_ascii_uppercase();
+    }
+
+    /// Checks that two lists have the same elements, ignoring ASCII case.
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        self.inner.eq_ignore_ascii_case(&other.inner)
+    }
+
+    /// Returns a new list with every element converted to lower case, as in [`str::to_lowercase`].
+    #[must_use]
+    pub fn to_lowercase(&self) -> StrListBuf {
+        let mut result = StrListBuf::with_capacity(self.inner.len());
+
+        for element in self {
+            result.push(element.to_lowercase());
+        }
+
+        result
+    }
+
+    /// Returns a new list with every element converted to upper case, as in [`str::to_uppercase`].
+    #[must_use]
+    pub fn to_uppercase(&self) -> StrListBuf {
+        let mut result = StrListBuf::with_capacity(self.inner.len());
+
+        for element in self {
+            result.push(element.to_uppercase());
+        }
+
+        result
+    }
+
+    /// Concatenates all elements into a single `String`.
+    #[must_use]
+    pub fn concat(&self) -> String {
+        let mut result = String::with_capacity(self.inner.len() - self.len());
+
+        for element in self {
+            result.push_str(element);
+        }
+
+        result
+    }
+
+    /// Concatenates all elements into a single `String`, placing `separator` between each pair of
+    /// adjacent elements.
+    #[must_use]
+    pub fn join(&self, separator: &str) -> String {
+        let count = self.len();
+        let mut result = String::with_capacity(
+            self.inner.len() - count + separator.len() * count.saturating_sub(1),
+        );
+        let mut iter = self.iter();
+
+        if let Some(first) = iter.next() {
+            result.push_str(first);
+
+            for element in iter {
+                result.push_str(separator);
+                result.push_str(element);
+            }
+        }
+
+        result
+    }
+
+    /// Returns an object that displays the elements separated by `separator`, without allocating.
+    #[must_use]
+    pub fn display_joined<'a>(&'a self, separator: &'a str) -> Joined<'a> {
+        Joined {
+            list: self,
+            separator,
+        }
+    }
+
+    /// Creates a list containing the elements of `self` repeated `n` times, allocating exactly
+    /// once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting encoding length overflows.
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> StrListBuf {
+        StrListBuf {
+            inner: self.inner.repeat(n),
+        }
+    }
+
+    /// Collects the elements into a `Vec`, allocating exactly once.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<&str> {
+        let mut result = Vec::with_capacity(self.len());
+
+        result.extend(self.iter());
+
+        result
+    }
+
+    /// Copies the elements into a `Vec` of owned strings, allocating the `Vec` exactly once.
+    #[must_use]
+    pub fn to_string_vec(&self) -> Vec<String> {
+        let mut result = Vec::with_capacity(self.len());
+
+        result.extend(self.iter().map(str::to_owned));
+
+        result
+    }
+
+    #[must_use]
+    pub fn to_str_list_buf(&self) -> StrListBuf {
+        StrListBuf {
+            inner: self.inner.to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn into_str_list_buf(self: Box<Self>) -> StrListBuf {
+        let raw = Box::into_raw(self) as *mut [u8];
+
+        StrListBuf {
+            inner: unsafe { Box::from_raw(raw) }.into_vec(),
+        }
+    }
+}
+
+impl AsRef<StrList> for StrList {
+    fn as_ref(&self) -> &StrList {
+        self
+    }
+}
+
+impl AsMut<StrList> for StrList {
+    fn as_mut(&mut self) -> &mut StrList {
+        self
+    }
+}
+
+impl Debug for StrList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Displays the elements separated by `", "`.
+impl Display for StrList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.display_joined(", "), f)
+    }
+}
+
+impl Default for &StrList {
+    fn default() -> Self {
+        unsafe { StrList::from_bytes_unchecked(&[]) }
+    }
+}
+
+impl Default for &mut StrList {
+    fn default() -> Self {
+        unsafe { StrList::from_bytes_unchecked_mut(&mut []) }
+    }
+}
+
+impl Clone for Box<StrList> {
+    fn clone(&self) -> Self {
+        self.to_str_list_buf().into_boxed_str_list()
+    }
+}
+
+impl Default for Box<StrList> {
+    fn default() -> Self {
+        StrListBuf::new().into_boxed_str_list()
+    }
+}
+
+impl From<&StrList> for Box<StrList> {
+    fn from(value: &StrList) -> Self {
+        value.to_str_list_buf().into_boxed_str_list()
+    }
+}
+
+impl From<StrListBuf> for Box<StrList> {
+    fn from(value: StrListBuf) -> Self {
+        value.into_boxed_str_list()
+    }
+}
+
+impl From<&StrList> for Arc<StrList> {
+    fn from(value: &StrList) -> Self {
+        let raw = Arc::into_raw(Arc::<[u8]>::from(&value.inner)) as *const StrList;
+
+        unsafe { Arc::from_raw(raw) }
+    }
+}
+
+impl From<StrListBuf> for Arc<StrList> {
+    fn from(value: StrListBuf) -> Self {
+        let raw = Arc::into_raw(Arc::<[u8]>::from(value.inner)) as *const StrList;
+
+        unsafe { Arc::from_raw(raw) }
+    }
+}
+
+impl From<&StrList> for Rc<StrList> {
+    fn from(value: &StrList) -> Self {
+        let raw = Rc::into_raw(Rc::<[u8]>::from(&value.inner)) as *const StrList;
+
+        unsafe { Rc::from_raw(raw) }
+    }
+}
+
+impl From<StrListBuf> for Rc<StrList> {
+    fn from(value: StrListBuf) -> Self {
+        let raw = Rc::into_raw(Rc::<[u8]>::from(value.inner)) as *const StrList;
+
+        unsafe { Rc::from_raw(raw) }
+    }
+}
+
+impl IntoIterator for Box<StrList> {
+    type Item = String;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_str_list_buf().into_iter()
+    }
+}
+
+impl Index<usize> for StrList {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl IndexMut<usize> for StrList {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match self.element_range(index) {
+            Some(range) => unsafe {
+                str::from_utf8_unchecked_mut(self.inner.get_unchecked_mut(range))
+            },
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len()
+            ),
+        }
+    }
+}
+
+macro_rules! impl_range_index {
+    ($($range:ty),*) => {$(
+        impl Index<$range> for StrList {
+            type Output = StrList;
+
+            fn index(&self, index: $range) -> &Self::Output {
+                self.get_range(index).expect("element range out of bounds")
+            }
+        }
+
+        impl IndexMut<$range> for StrList {
+            fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                self.get_range_mut(index).expect("element range out of bounds")
+            }
+        }
+    )*};
+}
+
+impl_range_index!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>
+);
+
+impl<'a> IntoIterator for &'a StrList {
+    type Item = &'a str;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut StrList {
+    type Item = &'a mut str;
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl PartialOrd for StrList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl Ord for StrList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl ToOwned for StrList {
+    type Owned = StrListBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_str_list_buf()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for &'a StrList {
+    type Error = FromBytesError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        StrList::from_bytes(value)
+    }
+}
+
+impl<'a> TryFrom<&'a mut [u8]> for &'a mut StrList {
+    type Error = FromBytesError;
+
+    fn try_from(value: &'a mut [u8]) -> Result<Self, Self::Error> {
+        StrList::from_bytes_mut(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    inner: &'a StrList,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.split_first().map(|(first, rest)| {
+            self.inner = rest;
+
+            first
+        })
+    }
+
+    /// Every element occupies at least its delimiter byte, so the number of remaining bytes is an
+    /// upper bound on the number of remaining elements.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.inner.inner.len();
+
+        (usize::from(remaining != 0), Some(remaining))
+    }
+
+    fn count(self) -> usize {
+        self.inner.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let bytes = &self.inner.inner;
+        let start = self.inner.element_start(n).unwrap_or(bytes.len());
+
+        self.inner = unsafe { StrList::from_bytes_unchecked(bytes.get_unchecked(start..)) };
+
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.split_last().map(|(last, rest)| {
+            self.inner = rest;
+
+            last
+        })
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let end = self.inner.element_start_back(n).unwrap_or(0);
+
+        self.inner =
+            unsafe { StrList::from_bytes_unchecked(self.inner.inner.get_unchecked(..end)) };
+
+        self.next_back()
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+#[derive(Clone, Debug)]
+pub struct CountedIter<'a> {
+    inner: Iter<'a>,
+    remaining: usize,
+}
+
+impl<'a> Iterator for CountedIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.inner.next();
+
+        if result.is_some() {
+            self.remaining -= 1;
+        }
+
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let result = self.inner.nth(n);
+
+        self.remaining = self.remaining.saturating_sub(n + 1);
+
+        result
+    }
+}
+
+impl DoubleEndedIterator for CountedIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let result = self.inner.next_back();
+
+        if result.is_some() {
+            self.remaining -= 1;
+        }
+
+        result
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let result = self.inner.nth_back(n);
+
+        self.remaining = self.remaining.saturating_sub(n + 1);
+
+        result
+    }
+}
+
+impl ExactSizeIterator for CountedIter<'_> {}
+
+impl FusedIterator for CountedIter<'_> {}
+
+#[derive(Debug)]
+pub struct IterMut<'a> {
+    inner: &'a mut StrList,
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = &'a mut str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = mem::take(&mut self.inner);
+
+        inner.split_first_mut().map(|(first, rest)| {
+            self.inner = rest;
+
+            first
+        })
+    }
+
+    /// Every element occupies at least its delimiter byte, so the number of remaining bytes is an
+    /// upper bound on the number of remaining elements.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.inner.inner.len();
+
+        (usize::from(remaining != 0), Some(remaining))
+    }
+
+    fn count(self) -> usize {
+        self.inner.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let inner = mem::take(&mut self.inner);
+        let start = inner.element_start(n).unwrap_or(inner.inner.len());
+
+        self.inner =
+            unsafe { StrList::from_bytes_unchecked_mut(inner.inner.get_unchecked_mut(start..)) };
+
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for IterMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let inner = mem::take(&mut self.inner);
+
+        inner.split_last_mut().map(|(last, rest)| {
+            self.inner = rest;
+
+            last
+        })
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let inner = mem::take(&mut self.inner);
+        let end = inner.element_start_back(n).unwrap_or(0);
+
+        self.inner =
+            unsafe { StrList::from_bytes_unchecked_mut(inner.inner.get_unchecked_mut(..end)) };
+
+        self.next_back()
+    }
+}
+
+impl FusedIterator for IterMut<'_> {}
+
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct StrListBuf {
+    inner: Vec<u8>,
+}
+
+impl StrListBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes the list can hold without reallocating, including delimiters.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes, including delimiters.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    /// Reserves capacity for exactly `additional` more bytes, including delimiters.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes, including delimiters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capacity overflows or the allocator reports a failure.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    /// Tries to reserve capacity for exactly `additional` more bytes, including delimiters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capacity overflows or the allocator reports a failure.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity to at least `min_capacity` bytes, or to the encoded length if that is
+    /// larger.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity);
+    }
+
+    /// Returns a builder for constructing a list in a single expression.
+    #[must_use]
+    pub const fn builder() -> StrListBufBuilder {
+        StrListBufBuilder { list: Self::new() }
+    }
+
+    /// Creates a `StrListBuf` from its underlying encoding without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be a valid encoding as described in [`StrList::as_bytes`].
+    #[must_use]
+    pub unsafe fn from_vec_unchecked(data: Vec<u8>) -> Self {
+        Self { inner: data }
+    }
+
+    /// Creates a `StrListBuf` from its underlying encoding, reusing the allocation of `data`.
+    ///
+    /// This is not offered as `TryFrom<Vec<u8>>`, because that would conflict with the
+    /// `From<Vec<S>>` impl that collects a vector of strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromVecError`] holding `data` if it is not a valid encoding as described in
+    /// [`StrList::as_bytes`].
+    pub fn from_vec(data: Vec<u8>) -> Result<Self, FromVecError> {
+        match StrList::from_bytes(&data) {
+            Ok(_) => Ok(Self { inner: data }),
+            Err(error) => Err(FromVecError { bytes: data, error }),
+        }
+    }
+
+    fn from_slice<S: AsRef<str>>(values: &[S]) -> Self {
+        let capacity = values.iter().map(|value| value.as_ref().len() + 1).sum();
+        let mut result = Self::with_capacity(capacity);
+
+        for value in values {
+            result.push(value.as_ref());
+        }
+
+        result
+    }
+
+    #[must_use]
+    pub fn into_boxed_str_list(self) -> Box<StrList> {
+        let raw = Box::into_raw(self.inner.into_boxed_slice()) as *mut _;
+
+        unsafe { Box::from_raw(raw) }
+    }
+
+    /// Consumes the list and leaks its buffer, returning a mutable reference to the elements.
+    /// Use [`Box::leak`] on the result of [`StrListBuf::into_boxed_str_list`] to also release the
+    /// unused capacity first.
+    #[must_use]
+    pub fn leak<'a>(self) -> &'a mut StrList {
+        unsafe { StrList::from_bytes_unchecked_mut(self.inner.leak()) }
+    }
+
+    /// Converts the list into its underlying encoding, as described in [`StrList::as_bytes`].
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.inner
+    }
+
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        self
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, compacting the list in a single pass.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.retain_mut(|element| f(element));
+    }
+
+    /// Same as [`StrListBuf::retain`], but `f` may modify the elements it visits.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut str) -> bool,
+    {
+        self.compact(|_, element| f(element));
+    }
+
+    /// Removes consecutive repeated elements.
+    pub fn dedup(&mut self) {
+        self.dedup_by(|element, previous| element == previous);
+    }
+
+    /// Removes consecutive elements for which `same_bucket(element, previous)` returns `true`,
+    /// where `previous` is the last element that was kept.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&str, &str) -> bool,
+    {
+        self.compact(|kept, element| {
+            kept.last()
+                .is_none_or(|previous| !same_bucket(element, previous))
+        });
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&str) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|element, previous| key(element) == key(previous));
+    }
+
+    /// Removes all repeated elements, keeping the first occurrence of each and preserving order.
+    pub fn dedup_all(&mut self) {
+        let mut seen = HashSet::new();
+        let mut keep = self
+            .iter()
+            .map(|element| seen.insert(element))
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        self.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    /// Removes leading and trailing whitespace from every element, compacting the list in a single
+    /// pass.
+    pub fn trim_in_place(&mut self) {
+        let length = self.inner.len();
+        let mut read = 0;
+        let mut write = 0;
+
+        while read < length {
+            let rest = unsafe { StrList::from_bytes_unchecked(self.inner.get_unchecked(read..)) };
+            let element = unsafe { rest.first().unwrap_unchecked() };
+            let end = read + element.len();
+            let trimmed = element.trim_start();
+            let start = end - trimmed.len();
+            let trimmed_length = trimmed.trim_end().len();
+
+            self.inner.copy_within(start..start + trimmed_length, write);
+            write += trimmed_length;
+            self.inner[write] = DELIMITER;
+            write += 1;
+            read = end + 1;
+        }
+
+        self.inner.truncate(write);
+    }
+
+    /// Removes all empty elements.
+    pub fn prune_empty(&mut self) {
+        let mut previous = DELIMITER;
+
+        self.inner.retain(|&byte| {
+            let keep = byte != DELIMITER || previous != DELIMITER;
+
+            previous = byte;
+
+            keep
+        });
+    }
+
+    /// Visits every element in order, keeping those for which `f` returns `true`. `f` also receives
+    /// the elements that have been kept so far.
+    fn compact<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&StrList, &mut str) -> bool,
+    {
+        struct Guard<'a> {
+            inner: &'a mut Vec<u8>,
+            read: usize,
+            write: usize,
+        }
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                let length = self.inner.len();
+
+                self.inner.copy_within(self.read..length, self.write);
+                self.inner.truncate(self.write + (length - self.read));
+            }
+        }
+
+        let mut guard = Guard {
+            inner: &mut self.inner,
+            read: 0,
+            write: 0,
+        };
+
+        while let Some(length) = find_delimiter(unsafe { guard.inner.get_unchecked(guard.read..) })
+        {
+            let (kept, rest) = guard.inner.split_at_mut(guard.read);
+            let kept = unsafe { StrList::from_bytes_unchecked(kept.get_unchecked(..guard.write)) };
+            let element = unsafe { str::from_utf8_unchecked_mut(rest.get_unchecked_mut(..length)) };
+            let end = guard.read + length + 1;
+
+            if f(kept, element) {
+                guard.inner.copy_within(guard.read..end, guard.write);
+                guard.write += end - guard.read;
+            }
+
+            guard.read = end;
+        }
+    }
+
+    /// Returns an iterator that removes and yields the elements for which `filter` returns `true`.
+    /// The elements for which `filter` returns `false` are kept in their original order.
+    ///
+    /// If the iterator is dropped before being fully consumed, the remaining elements are kept. If
+    /// the iterator is leaked, the list is left empty.
+    pub fn extract_if<F>(&mut self, filter: F) -> ExtractIf<'_, F>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let inner = mem::take(&mut self.inner);
+
+        ExtractIf {
+            list: self,
+            inner,
+            read: 0,
+            write: 0,
+            filter,
+        }
+    }
+
+    /// Starts appending a new element that is written in pieces through the returned
+    /// [`ElementWriter`]. The element is committed when the writer is finished or dropped.
+    ///
+    /// If the writer is leaked, the list is left empty.
+    pub fn start_element(&mut self) -> ElementWriter<'_> {
+        let inner = mem::take(&mut self.inner);
+        let start = inner.len();
+
+        ElementWriter {
+            list: self,
+            inner,
+            start,
+            pending: [0; 3],
+            pending_len: 0,
+        }
+    }
+
+    pub fn push<S: AsRef<str>>(&mut self, value: S) {
+        self.inner.extend(value.as_ref().as_bytes());
+        self.inner.push(DELIMITER);
+    }
+
+    /// Appends the formatted `arguments` as a new element, without an intermediate `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the list unchanged if a formatting trait implementation returns
+    /// an error.
+    pub fn push_fmt(&mut self, arguments: fmt::Arguments) -> fmt::Result {
+        struct Writer<'a> {
+            inner: &'a mut Vec<u8>,
+        }
+
+        impl fmt::Write for Writer<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.inner.extend_from_slice(s.as_bytes());
+
+                Ok(())
+            }
+        }
+
+        let length = self.inner.len();
+        let result = fmt::Write::write_fmt(
+            &mut Writer {
+                inner: &mut self.inner,
+            },
+            arguments,
+        );
+
+        if result.is_ok() {
+            self.inner.push(DELIMITER);
+        } else {
+            self.inner.truncate(length);
+        }
+
+        result
+    }
+
+    /// Appends the [`Display`] representation of `value` as a new element.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`StrListBuf::push_fmt`].
+    pub fn push_display<T: Display + ?Sized>(&mut self, value: &T) -> fmt::Result {
+        self.push_fmt(format_args!("{value}"))
+    }
+
+    /// Same as [`StrListBuf::push`], but reserves the needed capacity fallibly first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the list unchanged if the allocation fails.
+    pub fn try_push<S: AsRef<str>>(&mut self, value: S) -> Result<(), TryReserveError> {
+        let value = value.as_ref();
+
+        self.inner.try_reserve(value.len().saturating_add(1))?;
+        self.push(value);
+
+        Ok(())
+    }
+
+    /// Inserts `value` at position `index`, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of elements.
+    pub fn insert(&mut self, index: usize, value: &str) {
+        let position = self
+            .element_start(index)
+            .expect("insertion index out of bounds");
+
+        self.inner.splice(
+            position..position,
+            value.bytes().chain(iter::once(DELIMITER)),
+        );
+    }
+
+    /// Replaces the element at position `index` with `value`, moving the tail of the list only if
+    /// the lengths differ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: &str) {
+        let range = self
+            .element_range(index)
+            .expect("replacement index out of bounds");
+
+        self.replace_range(range, value);
+    }
+
+    /// Same as [`StrListBuf::set`], but returns the old element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, value: &str) -> String {
+        let range = self
+            .element_range(index)
+            .expect("replacement index out of bounds");
+        let result =
+            unsafe { str::from_utf8_unchecked(self.inner.get_unchecked(range.clone())) }.to_owned();
+
+        self.replace_range(range, value);
+
+        result
+    }
+
+    fn replace_range(&mut self, range: Range<usize>, value: &str) {
+        if range.len() == value.len() {
+            self.inner[range].copy_from_slice(value.as_bytes());
+        } else {
+            self.inner.splice(range, value.bytes());
+        }
+    }
+
+    /// Returns a guard that gives `String` access to the element at position `index`, or `None` if
+    /// `index` is out of bounds. The edited element is written back when the guard is dropped,
+    /// moving the tail of the list at most once.
+    pub fn element_mut(&mut self, index: usize) -> Option<ElementMut<'_>> {
+        let range = self.element_range(index)?;
+        let value =
+            unsafe { str::from_utf8_unchecked(self.inner.get_unchecked(range.clone())) }.to_owned();
+
+        Some(ElementMut {
+            list: self,
+            range,
+            value,
+        })
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the
+    /// left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> String {
+        let range = self
+            .element_range(index)
+            .expect("removal index out of bounds");
+        let result =
+            unsafe { str::from_utf8_unchecked(self.inner.get_unchecked(range.clone())) }.to_owned();
+        let length = self.inner.len();
+
+        self.inner.copy_within(range.end + 1..length, range.start);
+        self.inner.truncate(length - (range.len() + 1));
+
+        result
+    }
+
+    /// Removes and returns the element at position `index`, replacing it with the last element.
+    /// This does not preserve ordering, but only the last element is copied, and the bytes between
+    /// the two elements are moved only if the two elements have different lengths.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> String {
+        let range = self
+            .element_range(index)
+            .expect("swap_remove index out of bounds");
+        let result =
+            unsafe { str::from_utf8_unchecked(self.inner.get_unchecked(range.clone())) }.to_owned();
+        let length = self.inner.len();
+        let last_start = self.element_start_back(1).unwrap_or(0);
+
+        if last_start == range.start {
+            self.inner.truncate(range.start);
+        } else {
+            let removed_length = range.len() + 1;
+            let last_length = length - last_start;
+
+            if last_length <= removed_length {
+                self.inner.copy_within(last_start.., range.start);
+
+                if last_length != removed_length {
+                    self.inner
+                        .copy_within(range.end + 1..last_start, range.start + last_length);
+                }
+            } else {
+                self.inner
+                    .copy_within(last_start..last_start + removed_length, range.start);
+
+                self.inner[range.end + 1..].rotate_right(last_length - removed_length);
+            }
+
+            self.inner.truncate(length - removed_length);
+        }
+
+        result
+    }
+
+    pub fn push_front(&mut self, value: &str) {
+        self.inner
+            .splice(0..0, value.bytes().chain(iter::once(DELIMITER)));
+    }
+
+    pub fn pop_front(&mut self) -> Option<String> {
+        let first = self.first()?.to_owned();
+
+        self.inner.drain(..=first.len());
+
+        Some(first)
+    }
+
+    pub fn pop(&mut self) -> bool {
+        if let Some((_, rest)) = self.split_last() {
+            let length = rest.inner.len();
+
+            self.inner.truncate(length);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes the last element and returns it, or `None` if the list is empty.
+    pub fn pop_owned(&mut self) -> Option<String> {
+        let (last, rest) = self.split_last()?;
+        let result = last.to_owned();
+        let length = rest.inner.len();
+
+        self.inner.truncate(length);
+
+        Some(result)
+    }
+
+    /// Removes and returns the last element if `predicate` returns `true` for it.
+    pub fn pop_if<F>(&mut self, predicate: F) -> Option<String>
+    where
+        F: FnOnce(&mut str) -> bool,
+    {
+        if predicate(self.last_mut()?) {
+            self.pop_owned()
+        } else {
+            None
+        }
+    }
+
+    /// Keeps the first `len` elements and drops the rest. Has no effect if `len` is greater than or
+    /// equal to the number of elements.
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(end) = self.element_start(len) {
+            self.inner.truncate(end);
+        }
+    }
+
+    /// Splits the list into two at the given index, returning the elements `at..` and keeping the
+    /// elements `..at`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the number of elements.
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let position = self.element_start(at).expect("split index out of bounds");
+
+        Self {
+            inner: self.inner.split_off(position),
+        }
+    }
+
+    /// Copies all elements of `other` to the end of `self`.
+    pub fn extend_from_list(&mut self, other: &StrList) {
+        self.inner.extend_from_slice(&other.inner);
+    }
+
+    /// Moves all elements of `other` to the end of `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.inner.append(&mut other.inner);
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Removes the elements in `range` from the list, returning them as an iterator. The elements
+    /// are removed even if the iterator is not fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end or if the end is greater than the
+    /// number of elements.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_> {
+        let range = self.byte_range(range).expect("element range out of bounds");
+
+        Drain {
+            inner: self.inner.drain(range),
+        }
+    }
+
+    /// Replaces the elements in `range` with the elements of `replace_with`, returning the removed
+    /// elements as an iterator. The replacement is encoded up front so that the tail of the list is
+    /// moved only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end or if the end is greater than the
+    /// number of elements.
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> IntoIter
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let range = self.byte_range(range).expect("element range out of bounds");
+        let replacement = replace_with.into_iter().collect::<Self>();
+
+        IntoIter {
+            inner: self.inner.splice(range, replacement.inner).collect(),
+            start: 0,
+        }
+    }
+}
+
+impl Borrow<StrList> for StrListBuf {
+    fn borrow(&self) -> &StrList {
+        self
+    }
+}
+
+impl BorrowMut<StrList> for StrListBuf {
+    fn borrow_mut(&mut self) -> &mut StrList {
+        self
+    }
+}
+
+impl AsRef<StrList> for StrListBuf {
+    fn as_ref(&self) -> &StrList {
+        self
+    }
+}
+
+impl AsMut<StrList> for StrListBuf {
+    fn as_mut(&mut self) -> &mut StrList {
+        self
+    }
+}
+
+impl Debug for StrListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str_list(), f)
+    }
+}
+
+impl Display for StrListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.as_str_list(), f)
+    }
+}
+
+impl Deref for StrListBuf {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { StrList::from_bytes_unchecked(&self.inner) }
+    }
+}
+
+impl DerefMut for StrListBuf {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { StrList::from_bytes_unchecked_mut(&mut self.inner) }
+    }
+}
+
+impl<S: AsRef<str>> From<&[S]> for StrListBuf {
+    fn from(value: &[S]) -> Self {
+        Self::from_slice(value)
+    }
+}
+
+impl<S: AsRef<str>, const N: usize> From<[S; N]> for StrListBuf {
+    fn from(value: [S; N]) -> Self {
+        Self::from_slice(&value)
+    }
+}
+
+impl<S: AsRef<str>> From<Vec<S>> for StrListBuf {
+    fn from(value: Vec<S>) -> Self {
+        Self::from_slice(&value)
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for StrListBuf {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl IntoIterator for StrListBuf {
+    type Item = String;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.inner,
+            start: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a StrListBuf {
+    type Item = &'a str;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut StrListBuf {
+    type Item = &'a mut str;
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for StrListBuf {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a> Extend<&'a StrList> for StrListBuf {
+    fn extend<T: IntoIterator<Item = &'a StrList>>(&mut self, iter: T) {
+        for list in iter {
+            self.extend_from_list(list);
+        }
+    }
+}
+
+impl Add<&StrList> for StrListBuf {
+    type Output = Self;
+
+    fn add(mut self, rhs: &StrList) -> Self::Output {
+        self.extend_from_list(rhs);
+
+        self
+    }
+}
+
+impl Add<&StrList> for &StrList {
+    type Output = StrListBuf;
+
+    fn add(self, rhs: &StrList) -> Self::Output {
+        let mut result = StrListBuf::with_capacity(self.inner.len() + rhs.inner.len());
+
+        result.extend_from_list(self);
+        result.extend_from_list(rhs);
+
+        result
+    }
+}
+
+impl AddAssign<&StrList> for StrListBuf {
+    fn add_assign(&mut self, rhs: &StrList) {
+        self.extend_from_list(rhs);
+    }
+}
+
+impl Mul<usize> for &StrList {
+    type Output = StrListBuf;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        self.repeat(rhs)
+    }
+}
+
+impl PartialOrd for StrListBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StrListBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialEq<StrListBuf> for StrList {
+    fn eq(&self, other: &StrListBuf) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<StrList> for StrListBuf {
+    fn eq(&self, other: &StrList) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&StrList> for StrListBuf {
+    fn eq(&self, other: &&StrList) -> bool {
+        **self == **other
+    }
+}
+
+impl PartialEq<StrListBuf> for &StrList {
+    fn eq(&self, other: &StrListBuf) -> bool {
+        **self == **other
+    }
+}
+
+impl PartialOrd<StrListBuf> for StrList {
+    fn partial_cmp(&self, other: &StrListBuf) -> Option<Ordering> {
+        self.partial_cmp(&**other)
+    }
+}
+
+impl PartialOrd<StrList> for StrListBuf {
+    fn partial_cmp(&self, other: &StrList) -> Option<Ordering> {
+        (**self).partial_cmp(other)
+    }
+}
+
+macro_rules! impl_cmp_with_strs {
+    ($([$($generics:tt)*] $other:ty),*) => {$(
+        impl<$($generics)*> PartialEq<$other> for StrList {
+            fn eq(&self, other: &$other) -> bool {
+                self.iter().eq(other.iter().map(AsRef::as_ref))
+            }
+        }
+
+        impl<$($generics)*> PartialEq<StrList> for $other {
+            fn eq(&self, other: &StrList) -> bool {
+                *other == *self
+            }
+        }
+
+        impl<$($generics)*> PartialEq<$other> for StrListBuf {
+            fn eq(&self, other: &$other) -> bool {
+                **self == *other
+            }
+        }
+
+        impl<$($generics)*> PartialEq<StrListBuf> for $other {
+            fn eq(&self, other: &StrListBuf) -> bool {
+                **other == *self
+            }
+        }
+
+        impl<$($generics)*> PartialOrd<$other> for StrList {
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                Some(self.iter().cmp(other.iter().map(AsRef::as_ref)))
+            }
+        }
+
+        impl<$($generics)*> PartialOrd<StrList> for $other {
+            fn partial_cmp(&self, other: &StrList) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+
+        impl<$($generics)*> PartialOrd<$other> for StrListBuf {
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                (**self).partial_cmp(other)
+            }
+        }
+
+        impl<$($generics)*> PartialOrd<StrListBuf> for $other {
+            fn partial_cmp(&self, other: &StrListBuf) -> Option<Ordering> {
+                (**other).partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    )*};
+}
+
+impl_cmp_with_strs!(
+    [S: AsRef<str>] [S],
+    [S: AsRef<str>, const N: usize] [S; N],
+    [S: AsRef<str>] Vec<S>
+);
+
+/// Implements the mutable conversion traits for list types that also dereference mutably to
+/// [`StrList`].
+macro_rules! impl_str_list_storage_mut_traits {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> ::std::convert::AsMut<$crate::StrList> for $ty {
+            fn as_mut(&mut self) -> &mut $crate::StrList {
+                self
+            }
+        }
+
+        impl<$($generics)*> ::std::borrow::BorrowMut<$crate::StrList> for $ty {
+            fn borrow_mut(&mut self) -> &mut $crate::StrList {
+                self
+            }
+        }
+    };
+}
+
+/// Implements the conversion, formatting and comparison traits shared by the list types that
+/// dereference to [`StrList`] through some other storage.
+macro_rules! impl_str_list_storage_traits {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> ::std::convert::AsRef<$crate::StrList> for $ty {
+            fn as_ref(&self) -> &$crate::StrList {
+                self
+            }
+        }
+
+        impl<$($generics)*> ::std::borrow::Borrow<$crate::StrList> for $ty {
+            fn borrow(&self) -> &$crate::StrList {
+                self
+            }
+        }
+
+        impl<$($generics)*> ::std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Debug::fmt(self.as_str_list(), f)
+            }
+        }
+
+        impl<$($generics)*> ::std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(self.as_str_list(), f)
+            }
+        }
+
+        impl<$($generics)*> ::std::cmp::Eq for $ty {}
+
+        impl<$($generics)*> ::std::hash::Hash for $ty {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.as_str_list().hash(state);
+            }
+        }
+
+        impl<$($generics)*> ::std::cmp::PartialEq<$crate::StrList> for $ty {
+            fn eq(&self, other: &$crate::StrList) -> bool {
+                self.as_str_list() == other
+            }
+        }
+
+        impl<$($generics)*> ::std::cmp::PartialEq<$ty> for $crate::StrList {
+            fn eq(&self, other: &$ty) -> bool {
+                self == other.as_str_list()
+            }
+        }
+    };
+}
+
+/// Implements the growing and shrinking methods for list types whose `inner` field is a `Vec`-like
+/// byte buffer.
+#[cfg(any(feature = "allocator-api2", feature = "smallvec"))]
+macro_rules! impl_str_list_vec_methods {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> $ty {
+            pub fn push<S: AsRef<str>>(&mut self, value: S) {
+                self.inner.extend_from_slice(value.as_ref().as_bytes());
+                self.inner.push($crate::DELIMITER);
+            }
+
+            pub fn pop(&mut self) -> bool {
+                if let Some((_, rest)) = self.split_last() {
+                    let length = rest.inner.len();
+
+                    self.inner.truncate(length);
+
+                    true
+                } else {
+                    false
+                }
+            }
+
+            /// Keeps the first `len` elements and drops the rest. Has no effect if `len` is greater
+            /// than or equal to the number of elements.
+            pub fn truncate(&mut self, len: usize) {
+                if let Some(end) = self.element_start(len) {
+                    self.inner.truncate(end);
+                }
+            }
+
+            /// Copies all elements of `other` to the end of `self`.
+            pub fn extend_from_list(&mut self, other: &$crate::StrList) {
+                self.inner.extend_from_slice(&other.inner);
+            }
+
+            pub fn clear(&mut self) {
+                self.inner.clear();
+            }
+        }
+
+        impl<$($generics)*, S: AsRef<str>> ::std::iter::Extend<S> for $ty {
+            fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+                for value in iter {
+                    self.push(value);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "allocator-api2", feature = "smallvec"))]
+use {impl_str_list_storage_mut_traits, impl_str_list_storage_traits, impl_str_list_vec_methods};
+
+/// A list stored inline in a fixed-size buffer of `N` bytes, which never allocates.
+#[derive(Clone, Copy)]
+pub struct StrListArray<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StrListArray<N> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the total number of bytes the buffer can hold, including delimiters.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of bytes still available, including the one needed for a delimiter.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        self
+    }
+
+    /// Appends `value` to the end of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] and leaves the list unchanged if `value` and its delimiter do not
+    /// fit in the remaining capacity.
+    pub fn push<S: AsRef<str>>(&mut self, value: S) -> Result<(), CapacityError> {
+        let value = value.as_ref().as_bytes();
+
+        if value.len() < self.remaining_capacity() {
+            let end = self.len + value.len();
+
+            self.data[self.len..end].copy_from_slice(value);
+            self.data[end] = DELIMITER;
+            self.len = end + 1;
+
+            Ok(())
+        } else {
+            Err(CapacityError)
+        }
+    }
+
+    pub fn pop(&mut self) -> bool {
+        if let Some((_, rest)) = self.split_last() {
+            self.len = rest.inner.len();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Keeps the first `len` elements and drops the rest. Has no effect if `len` is greater than or
+    /// equal to the number of elements.
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(end) = self.element_start(len) {
+            self.len = end;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for StrListArray<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for StrListArray<N> {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { StrList::from_bytes_unchecked(self.data.get_unchecked(..self.len)) }
+    }
+}
+
+impl<const N: usize> DerefMut for StrListArray<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { StrList::from_bytes_unchecked_mut(self.data.get_unchecked_mut(..self.len)) }
+    }
+}
+
+impl<const N: usize> PartialEq for StrListArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
+
+impl_str_list_storage_traits!([const N: usize] StrListArray<N>);
+impl_str_list_storage_mut_traits!([const N: usize] StrListArray<N>);
+
+impl<const N: usize> TryFrom<&StrList> for StrListArray<N> {
+    type Error = CapacityError;
+
+    fn try_from(value: &StrList) -> Result<Self, Self::Error> {
+        let mut result = Self::new();
+        let length = value.inner.len();
+
+        result
+            .data
+            .get_mut(..length)
+            .ok_or(CapacityError)?
+            .copy_from_slice(&value.inner);
+
+        result.len = length;
+
+        Ok(result)
+    }
+}
+
+/// A reference-counted list that shares its storage, so clones and sub-lists don't copy any
+/// elements.
+#[derive(Clone)]
+pub struct SharedStrList {
+    data: Arc<[u8]>,
+    range: Range<usize>,
+}
+
+impl SharedStrList {
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        self
+    }
+
+    /// Returns the elements in `range` as a list sharing the same storage, or `None` if `range` is
+    /// out of bounds.
+    #[must_use]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        self.byte_range(range).map(|range| Self {
+            data: Arc::clone(&self.data),
+            range: self.range.start + range.start..self.range.start + range.end,
+        })
+    }
+
+    /// Returns a list sharing the same storage that contains the same elements as `sub_list`, which
+    /// must be borrowed from `self`, for example through [`StrList::get_range`] or
+    /// [`StrList::split_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sub_list` is not contained in `self`.
+    #[must_use]
+    pub fn slice_ref(&self, sub_list: &StrList) -> Self {
+        let start = self.inner.as_ptr() as usize;
+        let sub_start = sub_list.inner.as_ptr() as usize;
+
+        assert!(
+            sub_start >= start && sub_start + sub_list.inner.len() <= start + self.inner.len(),
+            "sub-list is not contained in the shared list"
+        );
+
+        let offset = self.range.start + (sub_start - start);
+
+        Self {
+            data: Arc::clone(&self.data),
+            range: offset..offset + sub_list.inner.len(),
+        }
+    }
+}
+
+impl Default for SharedStrList {
+    fn default() -> Self {
+        Self {
+            data: Arc::from([]),
+            range: 0..0,
+        }
+    }
+}
+
+impl Deref for SharedStrList {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { StrList::from_bytes_unchecked(self.data.get_unchecked(self.range.clone())) }
+    }
+}
+
+impl From<&StrList> for SharedStrList {
+    fn from(value: &StrList) -> Self {
+        Self {
+            data: Arc::from(&value.inner),
+            range: 0..value.inner.len(),
+        }
+    }
+}
+
+impl From<StrListBuf> for SharedStrList {
+    fn from(value: StrListBuf) -> Self {
+        let length = value.inner.len();
+
+        Self {
+            data: Arc::from(value.inner),
+            range: 0..length,
+        }
+    }
+}
+
+impl From<Arc<StrList>> for SharedStrList {
+    fn from(value: Arc<StrList>) -> Self {
+        let length = value.inner.len();
+        let data = unsafe { Arc::from_raw(Arc::into_raw(value) as *const [u8]) };
+
+        Self {
+            data,
+            range: 0..length,
+        }
+    }
+}
+
+impl PartialEq for SharedStrList {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
+
+impl_str_list_storage_traits!([] SharedStrList);
+
+/// A set of strings, stored as a [`StrList`] whose elements are sorted and distinct.
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct StrSet {
+    list: StrList,
+}
+
+impl StrSet {
+    /// Reinterprets `list` as a `StrSet` without checking that it is sorted and deduplicated.
+    ///
+    /// The elements of `list` should be strictly increasing. This is not a safety requirement: if
+    /// it does not hold, lookups and set operations give unspecified but memory-safe results.
+    #[must_use]
+    pub fn from_str_list_unchecked(list: &StrList) -> &Self {
+        unsafe { &*(ptr::from_ref(list) as *const Self) }
+    }
+
+    /// Reinterprets `list` as a `StrSet`, or returns `None` if its elements are not strictly
+    /// increasing.
+    #[must_use]
+    pub fn from_str_list(list: &StrList) -> Option<&Self> {
+        let mut previous = None;
+
+        for element in list {
+            if previous.is_some_and(|previous| previous >= element) {
+                return None;
+            }
+
+            previous = Some(element);
+        }
+
+        Some(Self::from_str_list_unchecked(list))
+    }
+
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        &self.list
+    }
+
+    /// Returns `true` if the set contains `value`, using binary search.
+    #[must_use]
+    pub fn contains(&self, value: &str) -> bool {
+        self.list.binary_search(value).is_ok()
+    }
+
+    /// Returns `true` if every element of `self` is in `other`.
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        let mut others = other.list.iter();
+
+        self.list.iter().all(|element| {
+            others
+                .find(|&other| other >= element)
+                .is_some_and(|other| other == element)
+        })
+    }
+
+    /// Returns `true` if every element of `other` is in `self`.
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no elements in common.
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        let mut lhs = self.list.iter();
+        let mut rhs = other.list.iter();
+        let mut l = lhs.next();
+        let mut r = rhs.next();
+
+        while let (Some(left), Some(right)) = (l, r) {
+            match left.cmp(right) {
+                Ordering::Less => l = lhs.next(),
+                Ordering::Equal => return false,
+                Ordering::Greater => r = rhs.next(),
+            }
+        }
+
+        true
+    }
+
+    /// Returns an iterator over the elements within `range`, in order. Use a pair of [`Bound`]s to
+    /// specify the range, for example `(Bound::Included("a"), Bound::Excluded("c"))`.
+    #[must_use]
+    pub fn range<R: RangeBounds<str>>(&self, range: R) -> Iter<'_> {
+        let start = match range.start_bound() {
+            Bound::Included(value) => self.list.binary_search(value).unwrap_or_else(|i| i),
+            Bound::Excluded(value) => self.list.binary_search(value).map_or_else(|i| i, |i| i + 1),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(value) => self.list.binary_search(value).map_or_else(|i| i, |i| i + 1),
+            Bound::Excluded(value) => self.list.binary_search(value).unwrap_or_else(|i| i),
+            Bound::Unbounded => self.list.len(),
+        };
+
+        self.list[start..end.max(start)].iter()
+    }
+}
+
+impl AsRef<StrList> for StrSet {
+    fn as_ref(&self) -> &StrList {
+        &self.list
+    }
+}
+
+impl Debug for StrSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(&self.list).finish()
+    }
+}
+
+impl Deref for StrSet {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<'a> IntoIterator for &'a StrSet {
+    type Item = &'a str;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+impl ToOwned for StrSet {
+    type Owned = StrSetBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        StrSetBuf {
+            list: self.list.to_str_list_buf(),
+        }
+    }
+}
+
+/// The owned version of [`StrSet`].
+#[derive(Clone, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct StrSetBuf {
+    list: StrListBuf,
+}
+
+impl StrSetBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            list: StrListBuf::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn as_str_set(&self) -> &StrSet {
+        self
+    }
+
+    #[must_use]
+    pub fn into_str_list_buf(self) -> StrListBuf {
+        self.list
+    }
+
+    /// Adds `value` to the set, returning `true` if it was not already present.
+    pub fn insert(&mut self, value: &str) -> bool {
+        match self.list.binary_search(value) {
+            Ok(_) => false,
+            Err(index) => {
+                self.list.insert(index, value);
+
+                true
+            }
+        }
+    }
+
+    /// Removes `value` from the set, returning `true` if it was present.
+    pub fn remove(&mut self, value: &str) -> bool {
+        match self.list.binary_search(value) {
+            Ok(index) => {
+                self.list.drain(index..=index);
+
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+}
+
+impl AsRef<StrList> for StrSetBuf {
+    fn as_ref(&self) -> &StrList {
+        &self.list
+    }
+}
+
+impl AsRef<StrSet> for StrSetBuf {
+    fn as_ref(&self) -> &StrSet {
+        self
+    }
+}
+
+impl Borrow<StrSet> for StrSetBuf {
+    fn borrow(&self) -> &StrSet {
+        self
+    }
+}
+
+impl Debug for StrSetBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str_set(), f)
+    }
+}
+
+impl Deref for StrSetBuf {
+    type Target = StrSet;
+
+    fn deref(&self) -> &Self::Target {
+        StrSet::from_str_list_unchecked(&self.list)
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for StrSetBuf {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        self.list.extend(iter);
+        self.list.sort_unstable();
+        self.list.dedup();
+    }
+}
+
+/// Sorts and deduplicates the elements.
+impl From<StrListBuf> for StrSetBuf {
+    fn from(mut value: StrListBuf) -> Self {
+        value.sort_unstable();
+        value.dedup();
+
+        Self { list: value }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for StrSetBuf {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<StrListBuf>())
+    }
+}
+
+impl<'a> IntoIterator for &'a StrSetBuf {
+    type Item = &'a str;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+/// A string-to-string map, stored as a [`StrList`] of alternating keys and values in insertion
+/// order. Lookups are linear, which suits small maps such as HTTP headers.
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct StrMap {
+    list: StrList,
+}
+
+impl StrMap {
+    /// Reinterprets `list` as a `StrMap` without checking that it has an even number of elements.
+    ///
+    /// `list` should have an even number of elements. This is not a safety requirement: with an odd
+    /// number, the trailing key has no value and lookups give unspecified but memory-safe results.
+    #[must_use]
+    pub fn from_str_list_unchecked(list: &StrList) -> &Self {
+        unsafe { &*(ptr::from_ref(list) as *const Self) }
+    }
+
+    /// Reinterprets `list` as a `StrMap`, or returns `None` if it has an odd number of elements.
+    #[must_use]
+    pub fn from_str_list(list: &StrList) -> Option<&Self> {
+        if list.len().is_multiple_of(2) {
+            Some(Self::from_str_list_unchecked(list))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        &self.list
+    }
+
+    /// Returns the number of key-value pairs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.list.len() / 2
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    fn key_index(&self, key: &str) -> Option<usize> {
+        self.list
+            .iter()
+            .step_by(2)
+            .position(|k| k == key)
+            .map(|i| i * 2)
+    }
+
+    /// Returns the value of the first pair whose key is `key`.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter()
+            .find_map(|(k, v)| if k == key { Some(v) } else { None })
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.key_index(key).is_some()
+    }
+
+    /// Returns an iterator over the key-value pairs, in insertion order.
+    #[must_use]
+    pub fn iter(&self) -> Pairs<'_> {
+        Pairs {
+            inner: self.list.iter(),
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.list.iter().step_by(2)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &str> {
+        self.list.iter().skip(1).step_by(2)
+    }
+}
+
+impl AsRef<StrList> for StrMap {
+    fn as_ref(&self) -> &StrList {
+        &self.list
+    }
+}
+
+impl Debug for StrMap {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a StrMap {
+    type Item = (&'a str, &'a str);
+    type IntoIter = Pairs<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl ToOwned for StrMap {
+    type Owned = StrMapBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        StrMapBuf {
+            list: self.list.to_str_list_buf(),
+        }
+    }
+}
+
+/// The owned version of [`StrMap`].
+#[derive(Clone, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct StrMapBuf {
+    list: StrListBuf,
+}
+
+impl StrMapBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            list: StrListBuf::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn as_str_map(&self) -> &StrMap {
+        self
+    }
+
+    /// Returns the underlying list of alternating keys and values.
+    #[must_use]
+    pub fn into_str_list_buf(self) -> StrListBuf {
+        self.list
+    }
+
+    /// Sets the value of the first pair whose key is `key`, returning the old value, or appends a
+    /// new pair if there is none.
+    pub fn insert(&mut self, key: &str, value: &str) -> Option<String> {
+        if let Some(index) = self.key_index(key) {
+            Some(self.list.replace(index + 1, value))
+        } else {
+            self.append(key, value);
+
+            None
+        }
+    }
+
+    /// Appends a pair without checking whether `key` is already present.
+    pub fn append(&mut self, key: &str, value: &str) {
+        self.list.push(key);
+        self.list.push(value);
+    }
+
+    /// Removes the first pair whose key is `key`, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let index = self.key_index(key)?;
+        let mut drain = self.list.drain(index..index + 2);
+
+        drain.next();
+
+        drain.next()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+}
+
+impl AsRef<StrList> for StrMapBuf {
+    fn as_ref(&self) -> &StrList {
+        &self.list
+    }
+}
+
+impl AsRef<StrMap> for StrMapBuf {
+    fn as_ref(&self) -> &StrMap {
+        self
+    }
+}
+
+impl Borrow<StrMap> for StrMapBuf {
+    fn borrow(&self) -> &StrMap {
+        self
+    }
+}
+
+impl Debug for StrMapBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str_map(), f)
+    }
+}
+
+impl Deref for StrMapBuf {
+    type Target = StrMap;
+
+    fn deref(&self) -> &Self::Target {
+        StrMap::from_str_list_unchecked(&self.list)
+    }
+}
+
+/// Inserts each pair with [`StrMapBuf::insert`].
+impl<K: AsRef<str>, V: AsRef<str>> Extend<(K, V)> for StrMapBuf {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key.as_ref(), value.as_ref());
+        }
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> FromIterator<(K, V)> for StrMapBuf {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl<'a> IntoIterator for &'a StrMapBuf {
+    type Item = (&'a str, &'a str);
+    type IntoIter = Pairs<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Pairs<'a> {
+    inner: Iter<'a>,
+}
+
+impl<'a> Iterator for Pairs<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.inner.next()?;
+
+        self.inner.next().map(|value| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+
+        (lower / 2, upper.map(|upper| upper / 2))
+    }
+}
+
+impl FusedIterator for Pairs<'_> {}
+
+/// A list of [`StrList`]s stored in a single buffer.
+///
+/// Each sub-list is stored as its [`StrList`] encoding followed by a single `0xfe` delimiter byte,
+/// which, like `0xff`, never appears in valid UTF-8.
+#[derive(Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct NestedStrList {
+    inner: [u8],
+}
+
+impl NestedStrList {
+    /// # Safety
+    ///
+    /// `data` must be a valid nested list encoding.
+    #[must_use]
+    pub unsafe fn from_bytes_unchecked(data: &[u8]) -> &Self {
+        &*(ptr::from_ref(data) as *const Self)
+    }
+
+    /// Checks that `data` is a valid nested list encoding and reinterprets it as a
+    /// `NestedStrList`.
+    ///
+    /// An empty sub-list is encoded as a lone `0xfe` delimiter, so adjacent delimiters are valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if some sub-list is not a valid [`StrList`] encoding, or if `data` does not
+    /// end with a `0xfe` delimiter.
+    pub fn from_bytes(data: &[u8]) -> Result<&Self, FromBytesError> {
+        let mut start = 0;
+
+        while start < data.len() {
+            let Some(length) = data[start..].iter().position(|&b| b == GROUP_DELIMITER) else {
+                return Err(FromBytesError::MissingDelimiter { offset: data.len() });
+            };
+
+            StrList::from_bytes(&data[start..start + length]).map_err(|error| match error {
+                FromBytesError::InvalidUtf8 { offset } => FromBytesError::InvalidUtf8 {
+                    offset: start + offset,
+                },
+                FromBytesError::MissingDelimiter { offset } => FromBytesError::MissingDelimiter {
+                    offset: start + offset,
+                },
+            })?;
+
+            start += length + 1;
+        }
+
+        Ok(unsafe { Self::from_bytes_unchecked(data) })
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the number of sub-lists.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&StrList> {
+        self.iter().nth(index)
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<&StrList> {
+        self.iter().next()
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<&StrList> {
+        self.iter().next_back()
+    }
+
+    /// Returns an iterator over the sub-lists.
+    #[must_use]
+    pub fn iter(&self) -> Groups<'_> {
+        Groups { rest: &self.inner }
+    }
+}
+
+impl AsRef<[u8]> for NestedStrList {
+    fn as_ref(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+impl Debug for NestedStrList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl Default for &NestedStrList {
+    fn default() -> Self {
+        unsafe { NestedStrList::from_bytes_unchecked(&[]) }
+    }
+}
+
+impl<'a> IntoIterator for &'a NestedStrList {
+    type Item = &'a StrList;
+    type IntoIter = Groups<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for NestedStrList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for NestedStrList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ToOwned for NestedStrList {
+    type Owned = NestedStrListBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        NestedStrListBuf {
+            inner: self.inner.to_vec(),
+        }
+    }
+}
+
+/// The owned version of [`NestedStrList`].
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct NestedStrListBuf {
+    inner: Vec<u8>,
+}
+
+impl NestedStrListBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn as_nested_str_list(&self) -> &NestedStrList {
+        self
+    }
+
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.inner
+    }
+
+    /// Appends a sub-list.
+    pub fn push(&mut self, list: &StrList) {
+        self.inner.reserve(list.inner.len() + 1);
+        self.inner.extend_from_slice(&list.inner);
+        self.inner.push(GROUP_DELIMITER);
+    }
+
+    /// Appends a sub-list made of the elements of `iter`.
+    pub fn push_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for element in iter {
+            self.inner.extend_from_slice(element.as_ref().as_bytes());
+            self.inner.push(DELIMITER);
+        }
+
+        self.inner.push(GROUP_DELIMITER);
+    }
+
+    /// Removes the last sub-list and returns it.
+    pub fn pop(&mut self) -> Option<StrListBuf> {
+        self.inner.pop()?;
+
+        let start = self
+            .inner
+            .iter()
+            .rposition(|&b| b == GROUP_DELIMITER)
+            .map_or(0, |i| i + 1);
+
+        Some(StrListBuf {
+            inner: self.inner.split_off(start),
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+impl AsRef<NestedStrList> for NestedStrListBuf {
+    fn as_ref(&self) -> &NestedStrList {
+        self
+    }
+}
+
+impl Borrow<NestedStrList> for NestedStrListBuf {
+    fn borrow(&self) -> &NestedStrList {
+        self
+    }
+}
+
+impl Debug for NestedStrListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_nested_str_list(), f)
+    }
+}
+
+impl Deref for NestedStrListBuf {
+    type Target = NestedStrList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { NestedStrList::from_bytes_unchecked(&self.inner) }
+    }
+}
+
+impl<L: AsRef<StrList>> Extend<L> for NestedStrListBuf {
+    fn extend<I: IntoIterator<Item = L>>(&mut self, iter: I) {
+        for list in iter {
+            self.push(list.as_ref());
+        }
+    }
+}
+
+impl<L: AsRef<StrList>> FromIterator<L> for NestedStrListBuf {
+    fn from_iter<I: IntoIterator<Item = L>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl<'a> IntoIterator for &'a NestedStrListBuf {
+    type Item = &'a StrList;
+    type IntoIter = Groups<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for NestedStrListBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for NestedStrListBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An iterator over the sub-lists of a [`NestedStrList`].
+#[derive(Clone, Debug)]
+pub struct Groups<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = &'a StrList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = self.rest.iter().position(|&b| b == GROUP_DELIMITER)?;
+        let (list, rest) = self.rest.split_at(length);
+
+        self.rest = &rest[1..];
+
+        Some(unsafe { StrList::from_bytes_unchecked(list) })
+    }
+}
+
+impl DoubleEndedIterator for Groups<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, rest) = self.rest.split_last()?;
+        let start = rest
+            .iter()
+            .rposition(|&b| b == GROUP_DELIMITER)
+            .map_or(0, |i| i + 1);
+        let (rest, list) = rest.split_at(start);
+
+        self.rest = rest;
+
+        Some(unsafe { StrList::from_bytes_unchecked(list) })
+    }
+}
+
+impl FusedIterator for Groups<'_> {}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        buffer.push(value.to_le_bytes()[0] | 0x80);
+        value >>= 7;
+    }
+
+    buffer.push(value.to_le_bytes()[0]);
+}
+
+/// Returns the decoded value and the number of bytes it occupies, or `None` if the varint is
+/// truncated, overflows `usize`, or is not in its shortest form.
+fn decode_varint(data: &[u8]) -> Option<(usize, usize)> {
+    let mut result = 0_usize;
+
+    for ((i, &byte), shift) in data.iter().enumerate().zip((0..usize::BITS).step_by(7)) {
+        let bits = usize::from(byte & 0x7f);
+        let shifted = bits << shift;
+
+        if shifted >> shift != bits {
+            return None;
+        }
+
+        result |= shifted;
+
+        if byte < 0x80 {
+            return if byte == 0 && i != 0 {
+                None
+            } else {
+                Some((result, i + 1))
+            };
+        }
+    }
+
+    None
+}
+
+/// A list of byte strings stored in a single buffer.
+///
+/// Each element is stored as its length, encoded as an unsigned LEB128 varint, followed by its
+/// bytes, so elements may contain any byte values. Unlike [`StrList`], the list can only be
+/// walked forwards.
+#[derive(Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct ByteList {
+    inner: [u8],
+}
+
+impl ByteList {
+    /// # Safety
+    ///
+    /// `data` must be a valid length-prefixed encoding.
+    #[must_use]
+    pub unsafe fn from_bytes_unchecked(data: &[u8]) -> &Self {
+        &*(ptr::from_ref(data) as *const Self)
+    }
+
+    /// Checks that `data` is a valid length-prefixed encoding and reinterprets it as a
+    /// `ByteList`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a length prefix is truncated, overflows `usize` or is not in its shortest
+    /// form, or if an element runs past the end of `data`. Rejecting longer forms keeps the encoding
+    /// of a list unique, so lists can be compared and hashed by their bytes.
+    pub fn from_bytes(data: &[u8]) -> Result<&Self, TruncatedError> {
+        let mut offset = 0;
+
+        while offset < data.len() {
+            match decode_varint(&data[offset..]) {
+                Some((length, header)) if length <= data.len() - offset - header => {
+                    offset += header + length;
+                }
+                _ => return Err(TruncatedError { offset }),
+            }
+        }
+
+        Ok(unsafe { Self::from_bytes_unchecked(data) })
+    }
+
+    /// Returns the underlying encoding of the list.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the number of elements in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        self.iter().nth(index)
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<&[u8]> {
+        self.iter().next()
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<&[u8]> {
+        self.iter().last()
+    }
+
+    #[must_use]
+    pub fn contains(&self, x: &[u8]) -> bool {
+        self.iter().any(|element| element == x)
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> ByteIter<'_> {
+        ByteIter { rest: &self.inner }
+    }
+
+    fn element_offsets(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut iter = self.iter();
+
+        iter::from_fn(move || {
+            let offset = self.inner.len() - iter.rest.len();
+
+            iter.next().map(|_| offset)
+        })
+    }
+
+    fn element_start(&self, index: usize) -> Option<usize> {
+        self.element_offsets()
+            .chain(iter::once(self.inner.len()))
+            .nth(index)
+    }
+
+    fn byte_range<R: RangeBounds<usize>>(&self, range: R) -> Option<Range<usize>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+
+        let byte_start = self.element_start(start)?;
+        let rest = unsafe { Self::from_bytes_unchecked(self.inner.get_unchecked(byte_start..)) };
+
+        let byte_length = match range.end_bound() {
+            Bound::Included(&end) => rest.element_start(end.checked_add(1)?.checked_sub(start)?)?,
+            Bound::Excluded(&end) => rest.element_start(end.checked_sub(start)?)?,
+            Bound::Unbounded => rest.inner.len(),
+        };
+
+        Some(byte_start..byte_start + byte_length)
+    }
+
+    /// Returns the range of the element at position `index`, including its length prefix, together
+    /// with the length of the prefix.
+    fn element_span(&self, index: usize) -> Option<(Range<usize>, usize)> {
+        let start = self.element_start(index)?;
+        let (length, header) = decode_varint(&self.inner[start..])?;
+
+        Some((start..start + header + length, header))
+    }
+
+    #[must_use]
+    pub fn to_byte_list_buf(&self) -> ByteListBuf {
+        ByteListBuf {
+            inner: self.inner.to_vec(),
+        }
+    }
+
+    /// Converts the list into a [`StrListBuf`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if some element is not valid UTF-8.
+    pub fn to_str_list_buf(&self) -> Result<StrListBuf, str::Utf8Error> {
+        let mut result = StrListBuf::with_capacity(self.inner.len());
+
+        for element in self {
+            result.push(str::from_utf8(element)?);
+        }
+
+        Ok(result)
+    }
+}
+
+impl AsRef<[u8]> for ByteList {
+    fn as_ref(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+impl AsRef<ByteList> for ByteList {
+    fn as_ref(&self) -> &ByteList {
+        self
+    }
+}
+
+impl Debug for ByteList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl Default for &ByteList {
+    fn default() -> Self {
+        unsafe { ByteList::from_bytes_unchecked(&[]) }
+    }
+}
+
+impl Index<usize> for ByteList {
+    type Output = [u8];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ByteList {
+    type Item = &'a [u8];
+    type IntoIter = ByteIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for ByteList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for ByteList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ToOwned for ByteList {
+    type Owned = ByteListBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_byte_list_buf()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for &'a ByteList {
+    type Error = TruncatedError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        ByteList::from_bytes(value)
+    }
+}
+
+/// The owned version of [`ByteList`].
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct ByteListBuf {
+    inner: Vec<u8>,
+}
+
+impl ByteListBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    /// Creates an empty list with room for `capacity` bytes of encoding.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[must_use]
+    pub fn as_byte_list(&self) -> &ByteList {
+        self
+    }
+
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.inner
+    }
+
+    pub fn push<T: AsRef<[u8]>>(&mut self, value: T) {
+        let value = value.as_ref();
+
+        encode_varint(&mut self.inner, value.len());
+        self.inner.extend_from_slice(value);
+    }
+
+    /// Removes the last element and returns it. This walks the whole list.
+    pub fn pop(&mut self) -> Option<Vec<u8>> {
+        let start = self.element_offsets().last()?;
+        let mut value = self.inner.split_off(start);
+        let (_, header) = decode_varint(&value)?;
+
+        value.drain(..header);
+
+        Some(value)
+    }
+
+    /// Keeps the first `len` elements and drops the rest.
+    pub fn truncate(&mut self, len: usize) {
+        let end = self.element_offsets().nth(len);
+
+        if let Some(end) = end {
+            self.inner.truncate(end);
+        }
+    }
+
+    /// Inserts `value` at position `index`, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of elements.
+    pub fn insert<T: AsRef<[u8]>>(&mut self, index: usize, value: T) {
+        let value = value.as_ref();
+        let position = self
+            .element_start(index)
+            .expect("insertion index out of bounds");
+        let mut header = Vec::new();
+
+        encode_varint(&mut header, value.len());
+
+        self.inner.splice(
+            position..position,
+            header.into_iter().chain(value.iter().copied()),
+        );
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the
+    /// left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Vec<u8> {
+        let (range, header) = self
+            .element_span(index)
+            .expect("removal index out of bounds");
+
+        self.inner.drain(range).skip(header).collect()
+    }
+
+    /// Removes and returns the element at position `index`, replacing it with the last element.
+    /// This does not preserve ordering, and walks the whole list to find the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Vec<u8> {
+        let (range, header) = self
+            .element_span(index)
+            .expect("swap_remove index out of bounds");
+        let result = self.inner[range.start + header..range.end].to_vec();
+        let last_start = self.element_offsets().last().unwrap_or(0);
+
+        if last_start == range.start {
+            self.inner.truncate(range.start);
+        } else {
+            let last = self.inner.split_off(last_start);
+
+            self.inner.splice(range, last);
+        }
+
+        result
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, compacting the list in a single pass.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        struct Guard<'a> {
+            inner: &'a mut Vec<u8>,
+            read: usize,
+            write: usize,
+        }
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                let length = self.inner.len();
+
+                self.inner.copy_within(self.read..length, self.write);
+                self.inner.truncate(self.write + (length - self.read));
+            }
+        }
+
+        let mut guard = Guard {
+            inner: &mut self.inner,
+            read: 0,
+            write: 0,
+        };
+
+        while let Some((length, header)) = decode_varint(&guard.inner[guard.read..]) {
+            let end = guard.read + header + length;
+
+            if f(&guard.inner[guard.read + header..end]) {
+                guard.inner.copy_within(guard.read..end, guard.write);
+                guard.write += end - guard.read;
+            }
+
+            guard.read = end;
+        }
+    }
+
+    /// Splits the list into two at the given index, returning the elements `at..` and keeping the
+    /// elements `..at`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the number of elements.
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let position = self.element_start(at).expect("split index out of bounds");
+
+        Self {
+            inner: self.inner.split_off(position),
+        }
+    }
+
+    pub fn extend_from_list(&mut self, other: &ByteList) {
+        self.inner.extend_from_slice(&other.inner);
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Removes the elements in `range` from the list, returning them as an iterator. The elements
+    /// are removed even if the iterator is not fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end or if the end is greater than the
+    /// number of elements.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> ByteDrain<'_> {
+        let range = self.byte_range(range).expect("element range out of bounds");
+
+        ByteDrain {
+            inner: self.inner.drain(range),
+        }
+    }
+
+    /// Sorts the elements in lexicographic order. This sort is stable.
+    pub fn sort(&mut self) {
+        self.permute(|elements| elements.sort());
+    }
+
+    /// Sorts the elements in lexicographic order. This sort is unstable, which only matters for
+    /// performance since equal elements are indistinguishable.
+    pub fn sort_unstable(&mut self) {
+        self.permute(|elements| elements.sort_unstable());
+    }
+
+    /// Reorders the elements with `f`, re-encoding the list into a new buffer.
+    fn permute<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut [&[u8]]),
+    {
+        let mut elements = self.iter().collect::<Vec<_>>();
+        let mut result = Self::with_capacity(self.inner.len());
+
+        f(&mut elements);
+        result.extend(elements);
+
+        *self = result;
+    }
+}
+
+impl AsRef<ByteList> for ByteListBuf {
+    fn as_ref(&self) -> &ByteList {
+        self
+    }
+}
+
+impl Borrow<ByteList> for ByteListBuf {
+    fn borrow(&self) -> &ByteList {
+        self
+    }
+}
+
+impl Debug for ByteListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_byte_list(), f)
+    }
+}
+
+impl Deref for ByteListBuf {
+    type Target = ByteList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { ByteList::from_bytes_unchecked(&self.inner) }
+    }
+}
+
+impl<T: AsRef<[u8]>> Extend<T> for ByteListBuf {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+impl From<&StrList> for ByteListBuf {
+    fn from(value: &StrList) -> Self {
+        let mut result = Self::with_capacity(value.inner.len());
+
+        result.extend(value.iter());
+
+        result
+    }
+}
+
+impl<T: AsRef<[u8]>> FromIterator<T> for ByteListBuf {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl<'a> IntoIterator for &'a ByteListBuf {
+    type Item = &'a [u8];
+    type IntoIter = ByteIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for ByteListBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for ByteListBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An iterator over the elements of a [`ByteList`].
+///
+/// Unlike [`Iter`], this iterator does not implement [`DoubleEndedIterator`]: a length prefix can
+/// only be decoded from its first byte, so the elements cannot be found from the end. It does not
+/// implement [`ExactSizeIterator`] either, since counting the elements walks the whole list.
+#[derive(Clone, Debug)]
+pub struct ByteIter<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for ByteIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (length, header) = decode_varint(self.rest)?;
+        let (element, rest) = self.rest[header..].split_at(length);
+
+        self.rest = rest;
+
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.rest.is_empty()), Some(self.rest.len()))
+    }
+}
+
+impl FusedIterator for ByteIter<'_> {}
+
+/// A draining iterator over the elements of a [`ByteListBuf`], created by [`ByteListBuf::drain`].
+pub struct ByteDrain<'a> {
+    inner: vec::Drain<'a, u8>,
+}
+
+impl ByteDrain<'_> {
+    #[must_use]
+    pub fn as_byte_list(&self) -> &ByteList {
+        unsafe { ByteList::from_bytes_unchecked(self.inner.as_slice()) }
+    }
+}
+
+impl Debug for ByteDrain<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ByteDrain")
+            .field(&self.as_byte_list())
+            .finish()
+    }
+}
+
+impl Iterator for ByteDrain<'_> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (length, header) = decode_varint(self.inner.as_slice())?;
+
+        Some(
+            self.inner
+                .by_ref()
+                .take(header + length)
+                .skip(header)
+                .collect(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_byte_list().iter().size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.as_byte_list().len()
+    }
+}
+
+impl FusedIterator for ByteDrain<'_> {}
+
+/// A list of [`OsStr`]s stored in a single buffer.
+///
+/// Elements are stored in a [`ByteList`] using their platform encoding (see
+/// [`OsStr::as_encoded_bytes`]), so any platform string can be held, including ones that contain
+/// `0xff` on Unix or unpaired surrogates on Windows.
+///
+/// Lists are compared element by element, as with `[&OsStr]`. Since each list has a unique
+/// encoding, equality and hashing work on the encoded bytes.
+#[derive(Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct OsStrList {
+    inner: ByteList,
+}
+
+impl OsStrList {
+    fn from_byte_list(list: &ByteList) -> &Self {
+        unsafe { &*(ptr::from_ref(list) as *const Self) }
+    }
+
+    /// Returns the elements in their platform encoding.
+    #[must_use]
+    pub fn as_byte_list(&self) -> &ByteList {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&OsStr> {
+        self.iter().nth(index)
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<&OsStr> {
+        self.iter().next()
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<&OsStr> {
+        self.iter().last()
+    }
+
+    /// Returns `true` if the list contains an element equal to `x`, comparing the platform strings
+    /// exactly.
+    #[must_use]
+    pub fn contains<S: AsRef<OsStr> + ?Sized>(&self, x: &S) -> bool {
+        self.inner.contains(x.as_ref().as_encoded_bytes())
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> OsIter<'_> {
+        OsIter {
+            inner: self.inner.iter(),
+        }
+    }
+}
+
+impl Debug for OsStrList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl Default for &OsStrList {
+    fn default() -> Self {
+        OsStrList::from_byte_list(<&ByteList>::default())
+    }
+}
+
+impl<'a> IntoIterator for &'a OsStrList {
+    type Item = &'a OsStr;
+    type IntoIter = OsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for OsStrList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for OsStrList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ToOwned for OsStrList {
+    type Owned = OsStrListBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        OsStrListBuf {
+            inner: self.inner.to_byte_list_buf(),
+        }
+    }
+}
+
+/// The owned version of [`OsStrList`].
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct OsStrListBuf {
+    inner: ByteListBuf,
+}
+
+impl OsStrListBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: ByteListBuf::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn as_os_str_list(&self) -> &OsStrList {
+        self
+    }
+
+    pub fn push<S: AsRef<OsStr>>(&mut self, value: S) {
+        self.inner.push(value.as_ref().as_encoded_bytes());
+    }
+
+    pub fn pop(&mut self) -> Option<OsString> {
+        self.inner
+            .pop()
+            .map(|value| unsafe { OsString::from_encoded_bytes_unchecked(value) })
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+impl AsRef<OsStrList> for OsStrListBuf {
+    fn as_ref(&self) -> &OsStrList {
+        self
+    }
+}
+
+impl Borrow<OsStrList> for OsStrListBuf {
+    fn borrow(&self) -> &OsStrList {
+        self
+    }
+}
+
+impl Debug for OsStrListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_os_str_list(), f)
+    }
+}
+
+impl Deref for OsStrListBuf {
+    type Target = OsStrList;
+
+    fn deref(&self) -> &Self::Target {
+        OsStrList::from_byte_list(&self.inner)
+    }
+}
+
+impl<S: AsRef<OsStr>> Extend<S> for OsStrListBuf {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+/// UTF-8 is a valid platform encoding on every platform, so the elements are copied as they are.
+impl From<&StrList> for OsStrListBuf {
+    fn from(value: &StrList) -> Self {
+        Self {
+            inner: ByteListBuf::from(value),
+        }
+    }
+}
+
+impl<S: AsRef<OsStr>> FromIterator<S> for OsStrListBuf {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl<'a> IntoIterator for &'a OsStrListBuf {
+    type Item = &'a OsStr;
+    type IntoIter = OsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for OsStrListBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for OsStrListBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OsIter<'a> {
+    inner: ByteIter<'a>,
+}
+
+impl<'a> Iterator for OsIter<'a> {
+    type Item = &'a OsStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|element| unsafe { OsStr::from_encoded_bytes_unchecked(element) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl FusedIterator for OsIter<'_> {}
+
+/// A list of [`Path`]s stored in a single buffer, built on [`OsStrList`].
+///
+/// Lists are compared and hashed element by element using [`Path`]'s component-wise comparison,
+/// so a list holding `a//b` equals one holding `a/b`, consistent with [`PathList::contains`]. Use
+/// [`PathList::as_os_str_list`] to compare the raw platform strings instead.
+#[repr(transparent)]
+pub struct PathList {
+    inner: OsStrList,
+}
+
+impl PathList {
+    fn from_os_str_list(list: &OsStrList) -> &Self {
+        unsafe { &*(ptr::from_ref(list) as *const Self) }
+    }
+
+    #[must_use]
+    pub fn as_os_str_list(&self) -> &OsStrList {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&Path> {
+        self.inner.get(index).map(Path::new)
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<&Path> {
+        self.inner.first().map(Path::new)
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<&Path> {
+        self.inner.last().map(Path::new)
+    }
+
+    /// Returns `true` if the list contains a path equal to `x`. Paths are compared by their
+    /// components, as with [`Path`]'s `PartialEq`.
+    #[must_use]
+    pub fn contains<P: AsRef<Path> + ?Sized>(&self, x: &P) -> bool {
+        let x = x.as_ref();
+
+        self.iter().any(|path| path == x)
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> PathIter<'_> {
+        PathIter {
+            inner: self.inner.iter(),
+        }
+    }
+}
+
+impl AsRef<OsStrList> for PathList {
+    fn as_ref(&self) -> &OsStrList {
+        &self.inner
+    }
+}
+
+impl Debug for PathList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl Default for &PathList {
+    fn default() -> Self {
+        PathList::from_os_str_list(<&OsStrList>::default())
+    }
+}
+
+impl Eq for PathList {}
+
+impl Hash for PathList {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+
+        for path in self {
+            path.hash(state);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PathList {
+    type Item = &'a Path;
+    type IntoIter = PathIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for PathList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for PathList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for PathList {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl ToOwned for PathList {
+    type Owned = PathListBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        PathListBuf {
+            inner: self.inner.to_owned(),
+        }
+    }
+}
+
+/// The owned version of [`PathList`].
+#[derive(Clone, Default)]
+pub struct PathListBuf {
+    inner: OsStrListBuf,
+}
+
+impl PathListBuf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: OsStrListBuf::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn as_path_list(&self) -> &PathList {
+        self
+    }
+
+    #[must_use]
+    pub fn into_os_str_list_buf(self) -> OsStrListBuf {
+        self.inner
+    }
+
+    pub fn push<P: AsRef<Path>>(&mut self, value: P) {
+        self.inner.push(value.as_ref());
+    }
+
+    pub fn pop(&mut self) -> Option<PathBuf> {
+        self.inner.pop().map(PathBuf::from)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+impl AsRef<PathList> for PathListBuf {
+    fn as_ref(&self) -> &PathList {
+        self
+    }
+}
+
+impl Borrow<PathList> for PathListBuf {
+    fn borrow(&self) -> &PathList {
+        self
+    }
+}
+
+impl Debug for PathListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_path_list(), f)
+    }
+}
+
+impl Deref for PathListBuf {
+    type Target = PathList;
+
+    fn deref(&self) -> &Self::Target {
+        PathList::from_os_str_list(&self.inner)
+    }
+}
+
+impl<P: AsRef<Path>> Extend<P> for PathListBuf {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+impl From<OsStrListBuf> for PathListBuf {
+    fn from(value: OsStrListBuf) -> Self {
+        Self { inner: value }
+    }
+}
+
+impl<P: AsRef<Path>> FromIterator<P> for PathListBuf {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl Eq for PathListBuf {}
+
+impl Hash for PathListBuf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+
+        for path in self {
+            path.hash(state);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PathListBuf {
+    type Item = &'a Path;
+    type IntoIter = PathIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Ord for PathListBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for PathListBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for PathListBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PathIter<'a> {
+    inner: OsIter<'a>,
+}
+
+impl<'a> Iterator for PathIter<'a> {
+    type Item = &'a Path;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Path::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl FusedIterator for PathIter<'_> {}
+
+#[derive(Clone)]
+pub struct FindMatching<'a, F> {
+    inner: iter::Enumerate<Iter<'a>>,
+    predicate: F,
+}
+
+impl<F> Debug for FindMatching<'_, F> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("FindMatching").field(&self.inner).finish()
+    }
+}
+
+impl<F> Iterator for FindMatching<'_, F>
+where
+    F: FnMut(&str) -> bool,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+
+        self.inner.find_map(|(index, element)| {
+            if predicate(element) {
+                Some(index)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<F> FusedIterator for FindMatching<'_, F> where F: FnMut(&str) -> bool {}
+
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    rest: &'a StrList,
+    chunk_size: usize,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a StrList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            None
+        } else {
+            let position = self
+                .rest
+                .element_start(self.chunk_size)
+                .unwrap_or(self.rest.inner.len());
+            let (chunk, rest) = self.rest.inner.split_at(position);
+
+            unsafe {
+                self.rest = StrList::from_bytes_unchecked(rest);
+
+                Some(StrList::from_bytes_unchecked(chunk))
+            }
+        }
+    }
+}
+
+impl FusedIterator for Chunks<'_> {}
+
+#[derive(Clone, Debug)]
+pub struct Windows<'a> {
+    rest: &'a StrList,
+    end: Option<usize>,
+}
+
+impl<'a> Iterator for Windows<'a> {
+    type Item = &'a StrList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.end?;
+        let data = &self.rest.inner;
+        let window = unsafe { StrList::from_bytes_unchecked(data.get_unchecked(..end)) };
+        let first_length = find_delimiter(data).map_or(0, |i| i + 1);
+        let rest = unsafe { data.get_unchecked(first_length..) };
+        let end = end - first_length;
+
+        self.end = find_delimiter(unsafe { rest.get_unchecked(end..) }).map(|i| end + i + 1);
+        self.rest = unsafe { StrList::from_bytes_unchecked(rest) };
+
+        Some(window)
+    }
+}
+
+impl FusedIterator for Windows<'_> {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Joined<'a> {
+    list: &'a StrList,
+    separator: &'a str,
+}
+
+impl Display for Joined<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut iter = self.list.iter();
+
+        if let Some(first) = iter.next() {
+            f.write_str(first)?;
+
+            for element in iter {
+                f.write_str(self.separator)?;
+                f.write_str(element)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct IntoIter {
+    inner: Vec<u8>,
+    start: usize,
+}
+
+impl IntoIter {
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        unsafe { StrList::from_bytes_unchecked(self.inner.get_unchecked(self.start..)) }
+    }
+}
+
+impl Debug for IntoIter {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIter")
+            .field(&self.as_str_list())
+            .finish()
+    }
+}
+
+impl Iterator for IntoIter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.as_str_list().first()?.to_owned();
+
+        self.start += first.len() + 1;
+
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str_list().iter().size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.as_str_list().len()
+    }
+}
+
+impl DoubleEndedIterator for IntoIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = self.as_str_list().split_last()?;
+        let last = last.to_owned();
+        let length = self.start + rest.inner.len();
+
+        self.inner.truncate(length);
+
+        Some(last)
+    }
+}
+
+impl FusedIterator for IntoIter {}
+
+pub struct Drain<'a> {
+    inner: vec::Drain<'a, u8>,
+}
+
+impl Drain<'_> {
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        unsafe { StrList::from_bytes_unchecked(self.inner.as_slice()) }
+    }
+}
+
+impl Debug for Drain<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_str_list()).finish()
+    }
+}
+
+impl Iterator for Drain<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.as_str_list().first()?.to_owned();
+
+        self.inner.nth(first.len());
+
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str_list().iter().size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.as_str_list().len()
+    }
+}
 
-            // TODO: Use `[T]::split_at_unchecked_mut`: https://github.com/rust-lang/rust/issues/76014.
+impl DoubleEndedIterator for Drain<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let last = self.as_str_list().last()?.to_owned();
 
-            let (left, right) = inner.split_at_mut(i);
+        self.inner.nth_back(last.len());
 
-            unsafe {
-                (
-                    str::from_utf8_unchecked_mut(right),
-                    Self::from_bytes_unchecked_mut(left),
-                )
-            }
-        })
+        Some(last)
     }
+}
 
-    #[must_use]
-    pub fn to_str_list_buf(&self) -> StrListBuf {
-        StrListBuf {
-            inner: self.inner.to_vec(),
-        }
-    }
+impl FusedIterator for Drain<'_> {}
+
+/// A by-value builder for [`StrListBuf`], created by [`StrListBuf::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct StrListBufBuilder {
+    list: StrListBuf,
 }
 
-impl AsRef<StrList> for StrList {
-    fn as_ref(&self) -> &StrList {
+impl StrListBufBuilder {
+    /// Appends `value` to the end of the list.
+    #[must_use]
+    pub fn push<S: AsRef<str>>(mut self, value: S) -> Self {
+        self.list.push(value);
+
         self
     }
-}
 
-impl AsMut<StrList> for StrList {
-    fn as_mut(&mut self) -> &mut StrList {
+    /// Appends every element of `iter` to the end of the list.
+    #[must_use]
+    pub fn extend<I>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.list.extend(iter);
+
         self
     }
+
+    #[must_use]
+    pub fn build(self) -> StrListBuf {
+        self.list
+    }
 }
 
-impl Debug for StrList {
+pub struct ElementMut<'a> {
+    list: &'a mut StrListBuf,
+    range: Range<usize>,
+    value: String,
+}
+
+impl Debug for ElementMut<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
+        f.debug_tuple("ElementMut").field(&self.value).finish()
     }
 }
 
-impl Default for &StrList {
-    fn default() -> Self {
-        unsafe { StrList::from_bytes_unchecked(&[]) }
+impl Deref for ElementMut<'_> {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
     }
 }
 
-impl Default for &mut StrList {
-    fn default() -> Self {
-        unsafe { StrList::from_bytes_unchecked_mut(&mut []) }
+impl DerefMut for ElementMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
     }
 }
 
-impl<'a> IntoIterator for &'a StrList {
-    type Item = &'a str;
-    type IntoIter = Iter<'a>;
+impl Drop for ElementMut<'_> {
+    fn drop(&mut self) {
+        let range = self.range.clone();
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+        self.list.replace_range(range, &self.value);
     }
 }
 
-impl<'a> IntoIterator for &'a mut StrList {
-    type Item = &'a mut str;
-    type IntoIter = IterMut<'a>;
+pub struct ElementWriter<'a> {
+    list: &'a mut StrListBuf,
+    inner: Vec<u8>,
+    start: usize,
+    pending: [u8; 3],
+    pending_len: usize,
+}
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+impl<'a> ElementWriter<'a> {
+    /// Returns an [`io::Write`] adapter that appends UTF-8 bytes to the element.
+    ///
+    /// The writer itself only implements [`fmt::Write`], so that `write!` is not ambiguous when
+    /// both traits are in scope.
+    pub fn as_io(&mut self) -> ElementIoWriter<'_, 'a> {
+        ElementIoWriter { writer: self }
     }
-}
 
-impl PartialOrd for StrList {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(Ord::cmp(self, other))
+    /// Returns the part of the element that has been written so far, without any incomplete
+    /// character left over from [`ElementWriter::as_io`].
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.inner.get_unchecked(self.start..)) }
     }
-}
 
-impl Ord for StrList {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.iter().cmp(other.iter())
+    /// Appends `s` to the element. An incomplete character left over from
+    /// [`ElementWriter::as_io`] is replaced with U+FFFD first.
+    pub fn push_str(&mut self, s: &str) {
+        self.replace_pending();
+        self.inner.extend_from_slice(s.as_bytes());
     }
-}
 
-impl ToOwned for StrList {
-    type Owned = StrListBuf;
+    /// Commits the element to the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error and leaves the list unchanged if
+    /// [`ElementWriter::as_io`] left an incomplete character at the end of the element.
+    pub fn finish(self) -> io::Result<()> {
+        if self.pending_len == 0 {
+            Ok(())
+        } else {
+            self.abort();
 
-    fn to_owned(&self) -> Self::Owned {
-        self.to_str_list_buf()
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "incomplete UTF-8 sequence at the end of the element",
+            ))
+        }
     }
-}
 
-pub struct Iter<'a> {
-    inner: &'a StrList,
-}
+    /// Discards the element, leaving the list unchanged.
+    pub fn abort(mut self) {
+        self.inner.truncate(self.start);
+        self.list.inner = mem::take(&mut self.inner);
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = &'a str;
+        mem::forget(self);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.split_first().map(|(first, rest)| {
-            self.inner = rest;
+    fn write_bytes(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut consumed = 0;
 
-            first
-        })
-    }
-}
+        if self.pending_len != 0 {
+            let pending_len = self.pending_len;
+            let take = buf.len().min(4 - pending_len);
+            let total = pending_len + take;
+            let mut char_bytes = [0; 4];
 
-impl<'a> DoubleEndedIterator for Iter<'a> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.inner.split_last().map(|(last, rest)| {
-            self.inner = rest;
+            char_bytes[..pending_len].copy_from_slice(&self.pending[..pending_len]);
+            char_bytes[pending_len..total].copy_from_slice(&buf[..take]);
 
-            last
-        })
+            let valid = match str::from_utf8(&char_bytes[..total]) {
+                Ok(_) => total,
+                Err(error) if error.valid_up_to() != 0 => error.valid_up_to(),
+                Err(error) if error.error_len().is_none() => {
+                    self.pending[..total].copy_from_slice(&char_bytes[..total]);
+                    self.pending_len = total;
+
+                    return Ok(buf.len());
+                }
+                Err(error) => return Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+            };
+
+            self.inner.extend_from_slice(&char_bytes[..valid]);
+            self.pending_len = 0;
+
+            consumed = valid - pending_len;
+        }
+
+        let rest = &buf[consumed..];
+
+        match str::from_utf8(rest) {
+            Ok(_) => {
+                self.inner.extend_from_slice(rest);
+
+                Ok(buf.len())
+            }
+            Err(error) => {
+                let (valid, tail) = rest.split_at(error.valid_up_to());
+
+                self.inner.extend_from_slice(valid);
+
+                if error.error_len().is_none() {
+                    self.pending[..tail.len()].copy_from_slice(tail);
+                    self.pending_len = tail.len();
+
+                    Ok(buf.len())
+                } else if consumed + valid.len() == 0 {
+                    Err(io::Error::new(io::ErrorKind::InvalidData, error))
+                } else {
+                    Ok(consumed + valid.len())
+                }
+            }
+        }
+    }
+
+    fn replace_pending(&mut self) {
+        if self.pending_len != 0 {
+            self.pending_len = 0;
+            self.inner.extend_from_slice(
+                char::REPLACEMENT_CHARACTER
+                    .encode_utf8(&mut [0; 4])
+                    .as_bytes(),
+            );
+        }
     }
 }
 
-pub struct IterMut<'a> {
-    inner: &'a mut StrList,
+impl Debug for ElementWriter<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ElementWriter")
+            .field(&self.as_str())
+            .finish()
+    }
 }
 
-impl<'a> Iterator for IterMut<'a> {
-    type Item = &'a mut str;
+/// Commits the element to the list, replacing an incomplete character left over from
+/// [`ElementWriter::as_io`] with U+FFFD.
+impl Drop for ElementWriter<'_> {
+    fn drop(&mut self) {
+        self.replace_pending();
+        self.inner.push(DELIMITER);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let inner = mem::take(&mut self.inner);
+        self.list.inner = mem::take(&mut self.inner);
+    }
+}
 
-        inner.split_first_mut().map(|(first, rest)| {
-            self.inner = rest;
+impl fmt::Write for ElementWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
 
-            first
-        })
+        Ok(())
     }
 }
 
-impl<'a> DoubleEndedIterator for IterMut<'a> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let inner = mem::take(&mut self.inner);
+/// An [`io::Write`] adapter for an [`ElementWriter`], returned by [`ElementWriter::as_io`].
+///
+/// A character may be split across calls to [`io::Write::write`]: up to three trailing bytes of an
+/// incomplete character are kept until the next call completes it. Bytes that can never be valid
+/// UTF-8 fail with [`io::ErrorKind::InvalidData`], after the valid text before them is written.
+#[derive(Debug)]
+pub struct ElementIoWriter<'w, 'a> {
+    writer: &'w mut ElementWriter<'a>,
+}
 
-        inner.split_last_mut().map(|(last, rest)| {
-            self.inner = rest;
+impl io::Write for ElementIoWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write_bytes(buf)
+    }
 
-            last
-        })
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
     }
 }
 
-#[derive(Clone, Default, Eq, Hash, PartialEq)]
-pub struct StrListBuf {
+pub struct ExtractIf<'a, F> {
+    list: &'a mut StrListBuf,
     inner: Vec<u8>,
+    read: usize,
+    write: usize,
+    filter: F,
 }
 
-impl StrListBuf {
-    #[must_use]
-    pub const fn new() -> Self {
-        Self { inner: Vec::new() }
+impl<F> ExtractIf<'_, F> {
+    fn remaining(&self) -> &StrList {
+        unsafe { StrList::from_bytes_unchecked(self.inner.get_unchecked(self.read..)) }
     }
+}
 
-    #[must_use]
-    pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            inner: Vec::with_capacity(capacity),
-        }
+impl<F> Debug for ExtractIf<'_, F> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ExtractIf").field(&self.remaining()).finish()
     }
+}
 
-    #[must_use]
-    pub fn into_boxed_str_list(self) -> Box<StrList> {
-        let raw = Box::into_raw(self.inner.into_boxed_slice()) as *mut _;
+impl<F> Iterator for ExtractIf<'_, F>
+where
+    F: FnMut(&str) -> bool,
+{
+    type Item = String;
 
-        unsafe { Box::from_raw(raw) }
-    }
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = unsafe { self.inner.get_unchecked(self.read..) };
+            let element = unsafe { StrList::from_bytes_unchecked(rest) }.first()?;
+            let end = self.read + element.len() + 1;
 
-    #[must_use]
-    pub fn as_str_list(&self) -> &StrList {
-        &self
+            if (self.filter)(element) {
+                let element = element.to_owned();
+
+                self.read = end;
+
+                return Some(element);
+            }
+
+            self.inner.copy_within(self.read..end, self.write);
+            self.write += end - self.read;
+            self.read = end;
+        }
     }
 
-    pub fn push(&mut self, value: &str) {
-        self.inner.extend(value.as_bytes());
-        self.inner.push(DELIMITER);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.remaining().iter().size_hint().1)
     }
+}
 
-    pub fn pop(&mut self) -> bool {
-        if let Some((_, rest)) = self.split_last() {
-            let length = rest.inner.len();
+impl<F> Drop for ExtractIf<'_, F> {
+    fn drop(&mut self) {
+        let length = self.inner.len();
 
-            self.inner.truncate(length);
+        self.inner.copy_within(self.read..length, self.write);
+        self.inner.truncate(self.write + (length - self.read));
 
-            true
-        } else {
-            false
-        }
+        self.list.inner = mem::take(&mut self.inner);
     }
+}
 
-    pub fn clear(&mut self) {
-        self.inner.clear();
-    }
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FromBytesError {
+    InvalidUtf8 { offset: usize },
+    MissingDelimiter { offset: usize },
+}
 
+impl FromBytesError {
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+    pub fn offset(&self) -> usize {
+        match *self {
+            Self::InvalidUtf8 { offset } | Self::MissingDelimiter { offset } => offset,
+        }
     }
 }
 
-impl Borrow<StrList> for StrListBuf {
-    fn borrow(&self) -> &StrList {
-        self
+impl Display for FromBytesError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Self::InvalidUtf8 { offset } => write!(f, "invalid UTF-8 at byte offset {offset}"),
+            Self::MissingDelimiter { offset } => {
+                write!(f, "missing trailing delimiter at byte offset {offset}")
+            }
+        }
     }
 }
 
-impl BorrowMut<StrList> for StrListBuf {
-    fn borrow_mut(&mut self) -> &mut StrList {
-        self
+impl Error for FromBytesError {}
+
+/// The error returned by [`StrListBuf::from_vec`], which gives back the rejected bytes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FromVecError {
+    bytes: Vec<u8>,
+    error: FromBytesError,
+}
+
+impl FromVecError {
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    #[must_use]
+    pub fn from_bytes_error(&self) -> FromBytesError {
+        self.error
     }
 }
 
-impl AsRef<StrList> for StrListBuf {
-    fn as_ref(&self) -> &StrList {
-        self
+impl Display for FromVecError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.error, f)
     }
 }
 
-impl AsMut<StrList> for StrListBuf {
-    fn as_mut(&mut self) -> &mut StrList {
-        self
+impl Error for FromVecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
     }
 }
 
-impl Debug for StrListBuf {
+/// The error returned when an element does not fit in a [`StrListArray`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CapacityError;
+
+impl Display for CapacityError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.deref().fmt(f)
+        f.write_str("insufficient capacity")
     }
 }
 
-impl Deref for StrListBuf {
-    type Target = StrList;
+impl Error for CapacityError {}
 
-    fn deref(&self) -> &Self::Target {
-        unsafe { StrList::from_bytes_unchecked(&self.inner) }
+/// The error returned by [`ByteList::from_bytes`] when a length prefix is malformed or the
+/// encoding ends in the middle of an element.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TruncatedError {
+    offset: usize,
+}
+
+impl TruncatedError {
+    /// Returns the byte offset of the element that could not be decoded.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
     }
 }
 
-impl DerefMut for StrListBuf {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { StrList::from_bytes_unchecked_mut(&mut self.inner) }
+impl Display for TruncatedError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "malformed or truncated element at byte offset {}",
+            self.offset
+        )
     }
 }
 
-impl<'a> FromIterator<&'a str> for StrListBuf {
-    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
-        let mut result = Self::new();
+impl Error for TruncatedError {}
 
-        result.extend(iter);
+#[doc(hidden)]
+pub mod __private {
+    use crate::DELIMITER;
+
+    #[must_use]
+    pub const fn encoded_len(elements: &[&str]) -> usize {
+        let mut result = 0;
+        let mut i = 0;
+
+        while i < elements.len() {
+            result += elements[i].len() + 1;
+            i += 1;
+        }
 
         result
     }
-}
 
-impl<'a> IntoIterator for &'a StrListBuf {
-    type Item = &'a str;
-    type IntoIter = Iter<'a>;
+    #[must_use]
+    pub const fn encode<const N: usize>(elements: &[&str]) -> [u8; N] {
+        let mut result = [0; N];
+        let mut position = 0;
+        let mut i = 0;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
-    }
-}
+        while i < elements.len() {
+            let bytes = elements[i].as_bytes();
+            let mut j = 0;
 
-impl<'a> IntoIterator for &'a mut StrListBuf {
-    type Item = &'a mut str;
-    type IntoIter = IterMut<'a>;
+            while j < bytes.len() {
+                result[position] = bytes[j];
+                position += 1;
+                j += 1;
+            }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+            result[position] = DELIMITER;
+            position += 1;
+            i += 1;
+        }
+
+        assert!(position == N, "encoded length mismatch");
+
+        result
     }
 }
 
-impl<'a> Extend<&'a str> for StrListBuf {
-    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
-        for value in iter {
-            self.push(value);
-        }
-    }
+/// Creates a `&'static` [`StrList`] from string constants, encoding it at compile time.
+///
+/// Elements are `&str`, which never contain the `0xff` delimiter since it is not valid UTF-8, so
+/// the result is always a valid encoding.
+#[macro_export]
+macro_rules! static_str_list {
+    ($($element:expr),* $(,)?) => {{
+        const ELEMENTS: &[&str] = &[$($element),*];
+        const BYTES: [u8; $crate::__private::encoded_len(ELEMENTS)] = $crate::__private::encode(ELEMENTS);
+        const LIST: &$crate::StrList = unsafe { $crate::StrList::from_bytes_unchecked(&BYTES) };
+
+        LIST
+    }};
 }
 
-impl PartialOrd for StrListBuf {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+/// Creates a [`StrListBuf`] containing the arguments, allocating exactly once.
+#[macro_export]
+macro_rules! str_list {
+    () => {
+        $crate::StrListBuf::new()
+    };
+    ($($element:expr),+ $(,)?) => {
+        $crate::StrListBuf::from([$($element),+])
+    };
 }
 
-impl Ord for StrListBuf {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.iter().cmp(other.iter())
-    }
+/// Creates a [`Box<StrList>`](StrList) containing the arguments, allocating exactly once.
+#[macro_export]
+macro_rules! boxed_str_list {
+    ($($element:expr),* $(,)?) => {
+        $crate::str_list![$($element),*].into_boxed_str_list()
+    };
 }
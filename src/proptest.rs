@@ -0,0 +1,16 @@
+//! Strategies for generating lists in property tests.
+
+use crate::StrListBuf;
+use proptest::collection::{self, SizeRange};
+use proptest::strategy::Strategy;
+
+/// Returns a strategy that generates lists with elements from `element` and a length within `size`.
+///
+/// Lists shrink by removing elements and by shrinking the remaining elements.
+pub fn str_list_buf<T>(element: T, size: impl Into<SizeRange>) -> impl Strategy<Value = StrListBuf>
+where
+    T: Strategy,
+    T::Value: AsRef<str>,
+{
+    collection::vec(element, size).prop_map(StrListBuf::from)
+}
@@ -0,0 +1,15 @@
+//! Generates and shrinks lists in quickcheck tests.
+
+use crate::StrListBuf;
+use quickcheck::{Arbitrary, Gen};
+
+/// Shrinks by removing elements and by shrinking the remaining elements.
+impl Arbitrary for StrListBuf {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::from(Vec::<String>::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_string_vec().shrink().map(Self::from))
+    }
+}
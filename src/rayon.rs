@@ -0,0 +1,220 @@
+//! Sorts elements in parallel and iterates over them with rayon.
+
+use crate::{find_delimiter, rfind_delimiter, Iter, IterMut, StrList, StrListBuf};
+use rayon::iter::plumbing::{bridge_unindexed, Folder, UnindexedConsumer, UnindexedProducer};
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+use std::collections::LinkedList;
+
+impl StrList {
+    /// Parallel version of [`StrList::sort`].
+    pub fn par_sort(&mut self) {
+        self.permute(|data, spans| {
+            spans.par_sort_by(|lhs, rhs| data[lhs.clone()].cmp(&data[rhs.clone()]));
+        });
+    }
+
+    /// Parallel version of [`StrList::sort_unstable`].
+    pub fn par_sort_unstable(&mut self) {
+        self.permute(|data, spans| {
+            spans.par_sort_unstable_by(|lhs, rhs| data[lhs.clone()].cmp(&data[rhs.clone()]));
+        });
+    }
+
+    fn split_position(&self) -> Option<usize> {
+        let middle = self.inner.len() / 2;
+
+        rfind_delimiter(&self.inner[..middle])
+            .or_else(|| {
+                find_delimiter(&self.inner[middle..self.inner.len().saturating_sub(1)])
+                    .map(|i| middle + i)
+            })
+            .map(|i| i + 1)
+    }
+}
+
+/// A parallel iterator over the elements of a [`StrList`], split on element boundaries.
+#[derive(Clone, Debug)]
+pub struct ParIter<'a> {
+    inner: &'a StrList,
+}
+
+impl<'a> ParallelIterator for ParIter<'a> {
+    type Item = &'a str;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge_unindexed(self, consumer)
+    }
+}
+
+impl<'a> UnindexedProducer for ParIter<'a> {
+    type Item = &'a str;
+
+    fn split(self) -> (Self, Option<Self>) {
+        match self.inner.split_position() {
+            None => (self, None),
+            Some(position) => {
+                let (left, right) = self.inner.inner.split_at(position);
+
+                unsafe {
+                    (
+                        Self {
+                            inner: StrList::from_bytes_unchecked(left),
+                        },
+                        Some(Self {
+                            inner: StrList::from_bytes_unchecked(right),
+                        }),
+                    )
+                }
+            }
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+    where
+        F: Folder<Self::Item>,
+    {
+        folder.consume_iter(Iter { inner: self.inner })
+    }
+}
+
+/// A parallel iterator over mutable references to the elements of a [`StrList`].
+#[derive(Debug)]
+pub struct ParIterMut<'a> {
+    inner: &'a mut StrList,
+}
+
+impl<'a> ParallelIterator for ParIterMut<'a> {
+    type Item = &'a mut str;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge_unindexed(self, consumer)
+    }
+}
+
+impl<'a> UnindexedProducer for ParIterMut<'a> {
+    type Item = &'a mut str;
+
+    fn split(self) -> (Self, Option<Self>) {
+        match self.inner.split_position() {
+            None => (self, None),
+            Some(position) => {
+                let (left, right) = self.inner.inner.split_at_mut(position);
+
+                unsafe {
+                    (
+                        Self {
+                            inner: StrList::from_bytes_unchecked_mut(left),
+                        },
+                        Some(Self {
+                            inner: StrList::from_bytes_unchecked_mut(right),
+                        }),
+                    )
+                }
+            }
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+    where
+        F: Folder<Self::Item>,
+    {
+        folder.consume_iter(IterMut { inner: self.inner })
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a StrList {
+    type Iter = ParIter<'a>;
+    type Item = &'a str;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { inner: self }
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a mut StrList {
+    type Iter = ParIterMut<'a>;
+    type Item = &'a mut str;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut { inner: self }
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a StrListBuf {
+    type Iter = ParIter<'a>;
+    type Item = &'a str;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { inner: self }
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a mut StrListBuf {
+    type Iter = ParIterMut<'a>;
+    type Item = &'a mut str;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut { inner: self }
+    }
+}
+
+impl<S> FromParallelIterator<S> for StrListBuf
+where
+    S: AsRef<str> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = S>,
+    {
+        let mut result = Self::new();
+
+        result.par_extend(par_iter);
+
+        result
+    }
+}
+
+/// Each thread collects into its own list, and the lists are concatenated in order at the end.
+impl<S> ParallelExtend<S> for StrListBuf
+where
+    S: AsRef<str> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = S>,
+    {
+        let lists = par_iter
+            .into_par_iter()
+            .fold(Self::new, |mut list, element| {
+                list.push(element);
+
+                list
+            })
+            .map(|list| {
+                let mut lists = LinkedList::new();
+
+                lists.push_back(list);
+
+                lists
+            })
+            .reduce(LinkedList::new, |mut lhs, mut rhs| {
+                lhs.append(&mut rhs);
+
+                lhs
+            });
+
+        self.inner
+            .reserve(lists.iter().map(|list| list.inner.len()).sum());
+
+        for mut list in lists {
+            self.append(&mut list);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Archives lists as their raw encoding so that they can be accessed in place.
+
+use crate::{StrList, StrListBuf};
+use rkyv::bytecheck::{CheckBytes, Verify};
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+use std::fmt::{self, Debug, Formatter};
+use std::ops::Deref;
+
+/// The archived form of [`StrListBuf`].
+#[derive(CheckBytes, Portable)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[rkyv(crate = rkyv)]
+#[repr(transparent)]
+pub struct ArchivedStrListBuf {
+    inner: ArchivedVec<u8>,
+}
+
+impl ArchivedStrListBuf {
+    /// Returns the archived list.
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        unsafe { StrList::from_bytes_unchecked(self.inner.as_slice()) }
+    }
+}
+
+impl AsRef<StrList> for ArchivedStrListBuf {
+    fn as_ref(&self) -> &StrList {
+        self.as_str_list()
+    }
+}
+
+impl Debug for ArchivedStrListBuf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str_list(), f)
+    }
+}
+
+impl Deref for ArchivedStrListBuf {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str_list()
+    }
+}
+
+impl PartialEq for ArchivedStrListBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
+
+impl Eq for ArchivedStrListBuf {}
+
+impl PartialEq<StrListBuf> for ArchivedStrListBuf {
+    fn eq(&self, other: &StrListBuf) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
+
+impl PartialEq<ArchivedStrListBuf> for StrListBuf {
+    fn eq(&self, other: &ArchivedStrListBuf) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
+
+unsafe impl<C> Verify<C> for ArchivedStrListBuf
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        match StrList::from_bytes(self.inner.as_slice()) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(Source::new(error)),
+        }
+    }
+}
+
+impl Archive for StrListBuf {
+    type Archived = ArchivedStrListBuf;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self.as_bytes(), resolver, unsafe { out.cast_unchecked() });
+    }
+}
+
+impl<S> Serialize<S> for StrListBuf
+where
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.as_bytes(), serializer)
+    }
+}
+
+impl<D> Deserialize<StrListBuf, D> for ArchivedStrListBuf
+where
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, _deserializer: &mut D) -> Result<StrListBuf, D::Error> {
+        Ok(self.as_str_list().to_str_list_buf())
+    }
+}
@@ -0,0 +1,88 @@
+//! Serializes lists as sequences of strings.
+//!
+//! To borrow a `&StrList` from the input, see [`crate::serde_bytes::borrowed`].
+
+use crate::{StrList, StrListBuf};
+use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Formatter};
+
+impl Serialize for StrList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter_counted())
+    }
+}
+
+impl Serialize for StrListBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str_list().serialize(serializer)
+    }
+}
+
+struct ElementSeed<'a> {
+    list: &'a mut StrListBuf,
+}
+
+impl<'de> DeserializeSeed<'de> for ElementSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for ElementSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        self.list.push(v);
+
+        Ok(())
+    }
+}
+
+struct ListVisitor;
+
+impl<'de> Visitor<'de> for ListVisitor {
+    type Value = StrListBuf;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of strings")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut result = StrListBuf::new();
+
+        while seq
+            .next_element_seed(ElementSeed { list: &mut result })?
+            .is_some()
+        {}
+
+        Ok(result)
+    }
+}
+
+impl<'de> Deserialize<'de> for StrListBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ListVisitor)
+    }
+}
@@ -0,0 +1,179 @@
+//! Serializes lists as their raw encoding in binary formats, for use with `#[serde(with = "...")]`.
+//!
+//! Human-readable formats still use the sequence representation.
+
+use crate::{StrList, StrListBuf};
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Formatter};
+
+/// Serializes `list` as a byte buffer, or as a sequence of strings if the format is human-readable.
+///
+/// # Errors
+///
+/// Returns any error reported by `serializer`.
+pub fn serialize<S>(list: &StrList, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        list.serialize(serializer)
+    } else {
+        serializer.serialize_bytes(list.as_bytes())
+    }
+}
+
+struct BytesVisitor;
+
+impl Visitor<'_> for BytesVisitor {
+    type Value = StrListBuf;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an encoded string list")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        StrList::from_bytes(v)
+            .map(StrList::to_str_list_buf)
+            .map_err(E::custom)
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match StrList::from_bytes(&v) {
+            Ok(_) => Ok(unsafe { StrListBuf::from_vec_unchecked(v) }),
+            Err(error) => Err(E::custom(error)),
+        }
+    }
+}
+
+/// Deserializes a list written by [`serialize`].
+///
+/// # Errors
+///
+/// Returns any error reported by `deserializer`, or an error if the byte buffer is not a valid
+/// encoding.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<StrListBuf, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        StrListBuf::deserialize(deserializer)
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+/// Like the parent module, but for `Cow<StrList>` fields, borrowing from the input when possible.
+pub mod cow {
+    use super::BytesVisitor;
+    use crate::StrList;
+    use serde::de::{Error, Visitor};
+    use serde::Deserializer;
+    use std::borrow::Cow;
+    use std::fmt::{self, Formatter};
+
+    pub use super::serialize;
+
+    struct CowVisitor;
+
+    impl<'de> Visitor<'de> for CowVisitor {
+        type Value = Cow<'de, StrList>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            formatter.write_str("an encoded string list")
+        }
+
+        fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            StrList::from_bytes(v).map(Cow::Borrowed).map_err(E::custom)
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            BytesVisitor.visit_bytes(v).map(Cow::Owned)
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            BytesVisitor.visit_byte_buf(v).map(Cow::Owned)
+        }
+    }
+
+    /// Deserializes a list written by [`serialize`], borrowing from the input if the format
+    /// allows it.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reported by `deserializer`, or an error if the byte buffer is not a
+    /// valid encoding.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Cow<'de, StrList>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            super::deserialize(deserializer).map(Cow::Owned)
+        } else {
+            deserializer.deserialize_bytes(CowVisitor)
+        }
+    }
+}
+
+/// Like the parent module, but for `&StrList` fields marked `#[serde(borrow)]`, borrowing from the
+/// input without copying.
+///
+/// Only binary formats that can lend out byte buffers are supported, since human-readable formats
+/// use the sequence representation.
+///
+/// There is no `Deserialize` impl for `&StrList` itself: it could only borrow the raw encoding,
+/// while `Serialize` for [`StrList`] writes a sequence of strings, so the two impls would not
+/// round-trip. Use `#[serde(borrow, with = "str_list::serde_bytes::borrowed")]` instead.
+pub mod borrowed {
+    use crate::StrList;
+    use serde::de::{Error, Visitor};
+    use serde::Deserializer;
+    use std::fmt::{self, Formatter};
+
+    pub use super::serialize;
+
+    struct BorrowedVisitor;
+
+    impl<'de> Visitor<'de> for BorrowedVisitor {
+        type Value = &'de StrList;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            formatter.write_str("a borrowed encoded string list")
+        }
+
+        fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            StrList::from_bytes(v).map_err(E::custom)
+        }
+    }
+
+    /// Deserializes a list written by [`serialize`], borrowing it from the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reported by `deserializer`, an error if the input cannot be borrowed, or
+    /// an error if the byte buffer is not a valid encoding.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<&'de StrList, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BorrowedVisitor)
+    }
+}
@@ -0,0 +1,116 @@
+//! A growable list that stores up to `N` bytes inline before spilling to the heap.
+
+use crate::{
+    impl_str_list_storage_mut_traits, impl_str_list_storage_traits, impl_str_list_vec_methods,
+    StrList, StrListBuf,
+};
+use smallvec::SmallVec;
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+/// Same as [`StrListBuf`], but backed by a `SmallVec<[u8; N]>`.
+///
+/// Only appending and truncating are available on the buffer itself. Elements can still be edited
+/// in place through [`StrList`], and [`SmallStrListBuf::into_str_list_buf`] gives access to the
+/// full [`StrListBuf`] API.
+#[derive(Clone)]
+pub struct SmallStrListBuf<const N: usize = 24> {
+    inner: SmallVec<[u8; N]>,
+}
+
+impl<const N: usize> SmallStrListBuf<N> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: SmallVec::new_const(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: SmallVec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns `true` if the encoding no longer fits inline and has been moved to the heap.
+    #[must_use]
+    pub fn spilled(&self) -> bool {
+        self.inner.spilled()
+    }
+
+    #[must_use]
+    pub fn as_str_list(&self) -> &StrList {
+        self
+    }
+
+    /// Converts the list into a [`StrListBuf`], reusing the heap buffer if it has spilled.
+    #[must_use]
+    pub fn into_str_list_buf(self) -> StrListBuf {
+        StrListBuf {
+            inner: self.inner.into_vec(),
+        }
+    }
+}
+
+impl_str_list_vec_methods!([const N: usize] SmallStrListBuf<N>);
+impl_str_list_storage_traits!([const N: usize] SmallStrListBuf<N>);
+impl_str_list_storage_mut_traits!([const N: usize] SmallStrListBuf<N>);
+
+impl<const N: usize> Default for SmallStrListBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for SmallStrListBuf<N> {
+    type Target = StrList;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { StrList::from_bytes_unchecked(&self.inner) }
+    }
+}
+
+impl<const N: usize> DerefMut for SmallStrListBuf<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { StrList::from_bytes_unchecked_mut(&mut self.inner) }
+    }
+}
+
+impl<const N: usize> From<&StrList> for SmallStrListBuf<N> {
+    fn from(value: &StrList) -> Self {
+        Self {
+            inner: SmallVec::from_slice(&value.inner),
+        }
+    }
+}
+
+impl<const N: usize> From<StrListBuf> for SmallStrListBuf<N> {
+    fn from(value: StrListBuf) -> Self {
+        Self {
+            inner: SmallVec::from_vec(value.inner),
+        }
+    }
+}
+
+impl<const N: usize> From<SmallStrListBuf<N>> for StrListBuf {
+    fn from(value: SmallStrListBuf<N>) -> Self {
+        value.into_str_list_buf()
+    }
+}
+
+impl<const N: usize, S: AsRef<str>> FromIterator<S> for SmallStrListBuf<N> {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<SmallStrListBuf<M>> for SmallStrListBuf<N> {
+    fn eq(&self, other: &SmallStrListBuf<M>) -> bool {
+        self.as_str_list() == other.as_str_list()
+    }
+}
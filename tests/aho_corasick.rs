@@ -0,0 +1,33 @@
+#![cfg(feature = "aho-corasick")]
+
+use aho_corasick::{AhoCorasickBuilder, MatchKind, StartKind};
+use str_list::aho_corasick::StrPatterns;
+use str_list::str_list;
+
+#[test]
+fn test_filter_containing_any() {
+    let list = str_list!["apple", "", "banana", "cherry", "grape"];
+    let patterns = StrPatterns::new(["an", "ap"]).unwrap();
+
+    assert_eq!(
+        list.filter_containing_any(&patterns).collect::<Vec<_>>(),
+        [(0, "apple"), (2, "banana"), (4, "grape")]
+    );
+}
+
+#[test]
+fn test_with_builder_anchored() {
+    let list = str_list!["xab", "ab", "c"];
+    let mut builder = AhoCorasickBuilder::new();
+
+    builder
+        .start_kind(StartKind::Anchored)
+        .match_kind(MatchKind::LeftmostFirst);
+
+    let patterns = StrPatterns::with_builder(&builder, ["ab"]).unwrap();
+
+    assert_eq!(
+        list.filter_containing_any(&patterns).collect::<Vec<_>>(),
+        [(0, "xab"), (1, "ab")]
+    );
+}
@@ -0,0 +1,228 @@
+use std::panic::{self, AssertUnwindSafe};
+use str_list::{ByteList, ByteListBuf};
+
+#[test]
+fn test_from_bytes() {
+    let list = ByteList::from_bytes(b"\x02ab\x00\x01\xff").unwrap();
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(&list[0], b"ab");
+    assert_eq!(&list[1], b"");
+    assert_eq!(&list[2], b"\xff");
+}
+
+#[test]
+fn test_from_bytes_empty_element() {
+    let list = ByteList::from_bytes(b"\x00").unwrap();
+
+    assert_eq!(list.iter().collect::<Vec<_>>(), [b""]);
+
+    let mut buffer = ByteListBuf::new();
+
+    buffer.push(b"");
+
+    assert_eq!(buffer.as_bytes(), b"\x00");
+}
+
+#[test]
+fn test_from_bytes_truncated_prefix() {
+    assert_eq!(ByteList::from_bytes(b"\x80").unwrap_err().offset(), 0);
+    assert_eq!(
+        ByteList::from_bytes(b"\x01a\xff\xff").unwrap_err().offset(),
+        2
+    );
+}
+
+#[test]
+fn test_from_bytes_overlong_prefix() {
+    assert_eq!(ByteList::from_bytes(b"\x80\x00").unwrap_err().offset(), 0);
+    assert_eq!(
+        ByteList::from_bytes(b"\x00\x81\x00a").unwrap_err().offset(),
+        1
+    );
+}
+
+#[test]
+fn test_from_bytes_length_past_end() {
+    assert_eq!(ByteList::from_bytes(b"\x03ab").unwrap_err().offset(), 0);
+    assert_eq!(
+        ByteList::from_bytes(b"\x01a\x80\x01a")
+            .unwrap_err()
+            .offset(),
+        2
+    );
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_from_bytes_prefix_limit() {
+    // `usize::MAX` is in range, but no element can be that long.
+    let max = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01";
+
+    assert_eq!(ByteList::from_bytes(max).unwrap_err().offset(), 0);
+
+    // One more bit overflows `usize`.
+    let overflow = b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x02";
+
+    assert_eq!(ByteList::from_bytes(overflow).unwrap_err().offset(), 0);
+
+    // A continuation past the tenth byte is too long even with zero bits.
+    let too_long = b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x00";
+
+    assert_eq!(ByteList::from_bytes(too_long).unwrap_err().offset(), 0);
+}
+
+#[test]
+fn test_push_prefix_boundaries() {
+    let mut list = ByteListBuf::new();
+
+    list.push([b'a'; 127]);
+    list.push([b'b'; 128]);
+    list.push([b'c'; 16384]);
+
+    let bytes = list.as_bytes();
+
+    assert_eq!(bytes[0], 0x7f);
+    assert_eq!(bytes[128..130], [0x80, 0x01]);
+    assert_eq!(bytes[258..261], [0x80, 0x80, 0x01]);
+    assert_eq!(ByteList::from_bytes(bytes).unwrap(), list.as_byte_list());
+    assert_eq!(
+        list.iter().map(<[u8]>::len).collect::<Vec<_>>(),
+        [127, 128, 16384]
+    );
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut list = ["a", "bc"].iter().collect::<ByteListBuf>();
+
+    list.insert(0, b"");
+    list.insert(3, [b'x'; 200]);
+    list.insert(2, b"\xff");
+
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.remove(4), [b'x'; 200]);
+    assert_eq!(list.remove(0), b"");
+    assert_eq!(list.as_bytes(), b"\x01a\x01\xff\x02bc");
+}
+
+#[test]
+#[should_panic(expected = "insertion index out of bounds")]
+fn test_insert_out_of_bounds() {
+    ["a"].iter().collect::<ByteListBuf>().insert(2, b"b");
+}
+
+#[test]
+#[should_panic(expected = "removal index out of bounds")]
+fn test_remove_out_of_bounds() {
+    ["a"].iter().collect::<ByteListBuf>().remove(1);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut list = ["a", "bcd", "", "ef"].iter().collect::<ByteListBuf>();
+
+    assert_eq!(list.swap_remove(0), b"a");
+    assert_eq!(list, ["ef", "bcd", ""].iter().collect());
+
+    assert_eq!(list.swap_remove(1), b"bcd");
+    assert_eq!(list, ["ef", ""].iter().collect());
+
+    assert_eq!(list.swap_remove(1), b"");
+    assert_eq!(list.swap_remove(0), b"ef");
+    assert!(list.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "swap_remove index out of bounds")]
+fn test_swap_remove_out_of_bounds() {
+    ["a"].iter().collect::<ByteListBuf>().swap_remove(1);
+}
+
+#[test]
+fn test_retain() {
+    let mut list = ["", "a", "bc", "", "def"].iter().collect::<ByteListBuf>();
+
+    list.retain(|element| !element.is_empty());
+
+    assert_eq!(list.as_bytes(), b"\x01a\x02bc\x03def");
+}
+
+#[test]
+fn test_retain_panic() {
+    let mut list = ["a", "bc", "d", "efg"].iter().collect::<ByteListBuf>();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        list.retain(|element| {
+            assert_ne!(element, b"d");
+
+            element.len() != 1
+        });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(list.as_bytes(), b"\x02bc\x01d\x03efg");
+}
+
+#[test]
+fn test_split_off() {
+    let mut list = ["a", "bc", "d"].iter().collect::<ByteListBuf>();
+    let tail = list.split_off(1);
+
+    assert_eq!(list.as_bytes(), b"\x01a");
+    assert_eq!(tail.as_bytes(), b"\x02bc\x01d");
+    assert!(list.split_off(1).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "split index out of bounds")]
+fn test_split_off_out_of_bounds() {
+    let _ = ["a"].iter().collect::<ByteListBuf>().split_off(2);
+}
+
+#[test]
+fn test_drain() {
+    let mut list = ["a", "bc", "", "d", "ef"].iter().collect::<ByteListBuf>();
+
+    assert_eq!(
+        list.drain(1..=3).collect::<Vec<_>>(),
+        [b"bc".to_vec(), Vec::new(), b"d".to_vec()]
+    );
+
+    assert_eq!(list.as_bytes(), b"\x01a\x02ef");
+
+    let mut drain = list.drain(..);
+
+    assert_eq!(drain.next().unwrap(), b"a");
+
+    drop(drain);
+
+    assert!(list.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "element range out of bounds")]
+fn test_drain_out_of_bounds() {
+    ["a"].iter().collect::<ByteListBuf>().drain(..2);
+}
+
+#[test]
+fn test_sort() {
+    let mut list = ["b", "", "ab", "\u{e9}", "a"]
+        .iter()
+        .collect::<ByteListBuf>();
+
+    list.sort();
+
+    assert_eq!(list, ["", "a", "ab", "b", "\u{e9}"].iter().collect());
+
+    list.push(b"\x00");
+    list.sort_unstable();
+
+    assert_eq!(&list[1], b"\x00");
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+fn test_index_out_of_bounds() {
+    let _ = &["a"].iter().collect::<ByteListBuf>()[1];
+}
@@ -0,0 +1,58 @@
+use str_list::{str_list, FromBytesError, NestedStrList, NestedStrListBuf};
+
+#[test]
+fn test_from_bytes() {
+    let list = NestedStrList::from_bytes(b"a\xffbc\xff\xfe\xfe\xff\xfe").unwrap();
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.get(0).unwrap(), &*str_list!["a", "bc"]);
+    assert!(list.get(1).unwrap().is_empty());
+    assert_eq!(list.get(2).unwrap(), &*str_list![""]);
+}
+
+#[test]
+fn test_from_bytes_stray_group_delimiter() {
+    assert_eq!(
+        NestedStrList::from_bytes(b"a\xfeb\xff\xfe"),
+        Err(FromBytesError::MissingDelimiter { offset: 1 })
+    );
+
+    assert_eq!(
+        NestedStrList::from_bytes(b"ab\xff\xfec\xfed\xff\xfe"),
+        Err(FromBytesError::MissingDelimiter { offset: 5 })
+    );
+}
+
+#[test]
+fn test_from_bytes_missing_group_delimiter() {
+    assert_eq!(
+        NestedStrList::from_bytes(b"a\xff"),
+        Err(FromBytesError::MissingDelimiter { offset: 2 })
+    );
+
+    assert_eq!(
+        NestedStrList::from_bytes(b"a\xff\xfeb\xff"),
+        Err(FromBytesError::MissingDelimiter { offset: 5 })
+    );
+}
+
+#[test]
+fn test_from_bytes_invalid_utf8() {
+    assert_eq!(
+        NestedStrList::from_bytes(b"a\xff\xfeb\xc3\xff\xfe"),
+        Err(FromBytesError::InvalidUtf8 { offset: 4 })
+    );
+}
+
+#[test]
+fn test_from_bytes_adjacent_empty_groups() {
+    let list = NestedStrList::from_bytes(b"\xfe\xfe").unwrap();
+    let mut expected = NestedStrListBuf::new();
+
+    expected.push(&str_list![]);
+    expected.push(&str_list![]);
+
+    assert_eq!(list, &*expected);
+    assert_eq!(list.len(), 2);
+    assert!(list.iter().all(|group| group.is_empty()));
+}
@@ -0,0 +1,63 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use str_list::{str_list, SharedStrList, StrList};
+
+fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[test]
+fn test_traits_match_str_list() {
+    let list = str_list!["a", "bc"];
+    let shared = SharedStrList::from(list.clone());
+    let borrowed: &StrList = shared.as_ref();
+
+    assert_eq!(format!("{shared:?}"), format!("{list:?}"));
+    assert_eq!(format!("{shared}"), format!("{list}"));
+    assert_eq!(hash(&shared), hash(&*list));
+    assert_eq!(shared, *list);
+    assert_eq!(*list, shared);
+    assert_eq!(borrowed, &*list);
+}
+
+#[test]
+fn test_slice() {
+    let shared = SharedStrList::from(str_list!["a", "bc", "", "d"]);
+
+    assert_eq!(shared.slice(1..3).unwrap(), *str_list!["bc", ""]);
+    assert_eq!(shared.slice(4..).unwrap(), *str_list![]);
+    assert!(shared.slice(..5).is_none());
+}
+
+#[test]
+fn test_slice_ref() {
+    let shared = SharedStrList::from(str_list!["a", "bc", "", "d"]);
+    let (_, tail) = shared.split_at(1);
+    let sliced = shared.slice_ref(tail);
+
+    assert_eq!(sliced, *str_list!["bc", "", "d"]);
+    assert_eq!(sliced.slice_ref(sliced.split_at(1).0), *str_list!["bc"]);
+    assert_eq!(shared.slice_ref(&shared), shared);
+}
+
+#[test]
+#[should_panic(expected = "sub-list is not contained in the shared list")]
+fn test_slice_ref_foreign() {
+    let shared = SharedStrList::from(str_list!["a", "bc"]);
+    let other = str_list!["a", "bc"];
+
+    let _ = shared.slice_ref(&other);
+}
+
+#[test]
+#[should_panic(expected = "sub-list is not contained in the shared list")]
+fn test_slice_ref_outside_slice() {
+    let shared = SharedStrList::from(str_list!["a", "bc", "d"]);
+    let head = shared.slice(..1).unwrap();
+
+    let _ = head.slice_ref(shared.split_at(1).1);
+}
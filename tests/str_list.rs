@@ -0,0 +1,93 @@
+use std::slice::GetDisjointMutError;
+use str_list::str_list;
+
+#[test]
+fn test_swap() {
+    let mut list = str_list!["a", "bcd", "", "ef"];
+
+    list.swap(0, 3);
+
+    assert_eq!(list, str_list!["ef", "bcd", "", "a"]);
+
+    list.swap(2, 1);
+
+    assert_eq!(list, str_list!["ef", "", "bcd", "a"]);
+
+    list.swap(0, 1);
+
+    assert_eq!(list, str_list!["", "ef", "bcd", "a"]);
+
+    list.swap(3, 3);
+
+    assert_eq!(list, str_list!["", "ef", "bcd", "a"]);
+}
+
+#[test]
+fn test_swap_multibyte() {
+    let mut list = str_list!["wörld", "x", "😀"];
+
+    list.swap(0, 2);
+
+    assert_eq!(list, str_list!["😀", "x", "wörld"]);
+}
+
+#[test]
+#[should_panic(expected = "swap index out of bounds")]
+fn test_swap_out_of_bounds() {
+    str_list!["a", "b"].swap(0, 2);
+}
+
+#[test]
+fn test_get_disjoint_mut() {
+    let mut list = str_list!["ab", "", "cd", "e", "fgh"];
+    let [first, middle, last] = list.get_disjoint_mut([0, 2, 4]).unwrap();
+
+    assert_eq!((&*first, &*middle, &*last), ("ab", "cd", "fgh"));
+
+    first.make_ascii_uppercase();
+    last.make_ascii_uppercase();
+
+    let [e, c, empty] = list.get_disjoint_mut([3, 2, 1]).unwrap();
+
+    assert_eq!((&*e, &*c, &*empty), ("e", "cd", ""));
+
+    e.make_ascii_uppercase();
+
+    assert_eq!(list, str_list!["AB", "", "cd", "E", "FGH"]);
+}
+
+#[test]
+fn test_get_disjoint_mut_overlapping() {
+    let mut list = str_list!["a", "b", "c"];
+
+    assert_eq!(
+        list.get_disjoint_mut([1, 1]),
+        Err(GetDisjointMutError::OverlappingIndices)
+    );
+    assert_eq!(
+        list.get_disjoint_mut([0, 2, 0]),
+        Err(GetDisjointMutError::OverlappingIndices)
+    );
+}
+
+#[test]
+fn test_get_disjoint_mut_out_of_bounds() {
+    let mut list = str_list!["a", "b", "c"];
+
+    assert_eq!(
+        list.get_disjoint_mut([0, 3]),
+        Err(GetDisjointMutError::IndexOutOfBounds)
+    );
+    assert_eq!(
+        str_list![].get_disjoint_mut([0]),
+        Err(GetDisjointMutError::IndexOutOfBounds)
+    );
+}
+
+#[test]
+fn test_get_disjoint_mut_empty() {
+    let mut list = str_list!["a"];
+
+    assert_eq!(list.get_disjoint_mut([]), Ok([]));
+    assert_eq!(str_list![].get_disjoint_mut([]), Ok([]));
+}
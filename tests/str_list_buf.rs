@@ -0,0 +1,291 @@
+use std::fmt::Write as _;
+use std::io::{ErrorKind, Write as _};
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use str_list::str_list;
+
+#[test]
+fn test_extract_if() {
+    let mut list = str_list!["", "a", "hello", "", "wörld", "xyz", "😀"];
+    let extracted = list
+        .extract_if(|element| element.len() > 2)
+        .collect::<Vec<_>>();
+
+    assert_eq!(extracted, ["hello", "wörld", "xyz", "😀"]);
+    assert_eq!(list, str_list!["", "a", ""]);
+    assert_eq!(list.as_bytes(), b"\xffa\xff\xff");
+}
+
+#[test]
+fn test_extract_if_dropped_early() {
+    let mut list = str_list!["", "a", "", "bc", ""];
+    let mut iter = list.extract_if(str::is_empty);
+
+    assert_eq!(iter.next().as_deref(), Some(""));
+    assert_eq!(iter.next().as_deref(), Some(""));
+
+    drop(iter);
+
+    assert_eq!(list, str_list!["a", "bc", ""]);
+}
+
+#[test]
+fn test_extract_if_panic() {
+    let mut list = str_list!["a", "bc", "d", "efg", "h"];
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        list.extract_if(|element| {
+            assert_ne!(element, "efg");
+
+            element.len() == 1
+        })
+        .for_each(drop);
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(list.as_bytes(), b"bc\xffefg\xffh\xff");
+}
+
+#[test]
+fn test_extract_if_leaked() {
+    let mut list = str_list!["a", "b"];
+    let mut iter = list.extract_if(|_| true);
+
+    iter.next();
+
+    mem::forget(iter);
+
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_retain() {
+    let mut list = str_list!["", "a", "bc", "", "def", "g"];
+
+    list.retain(|element| element.len() != 1);
+
+    assert_eq!(list, str_list!["", "bc", "", "def"]);
+
+    list.retain(|_| false);
+
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_retain_mut() {
+    let mut list = str_list!["a", "bc", "wörld"];
+
+    list.retain_mut(|element| {
+        element.make_ascii_uppercase();
+
+        element.len() != 1
+    });
+
+    assert_eq!(list, str_list!["BC", "WöRLD"]);
+}
+
+#[test]
+fn test_retain_panic() {
+    let mut list = str_list!["a", "bc", "d", "efg", "h"];
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        list.retain(|element| {
+            assert_ne!(element, "efg");
+
+            element.len() == 1
+        });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(list.as_bytes(), b"a\xffd\xffefg\xffh\xff");
+}
+
+#[test]
+fn test_retain_mut_panic() {
+    let mut list = str_list!["ab", "", "cd", "ef"];
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        list.retain_mut(|element| {
+            element.make_ascii_uppercase();
+
+            assert_ne!(element, "CD");
+
+            !element.is_empty()
+        });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(list.as_bytes(), b"AB\xffCD\xffef\xff");
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut list = str_list!["a", "bcd", "", "ef"];
+
+    assert_eq!(list.swap_remove(0), "a");
+    assert_eq!(list, str_list!["ef", "bcd", ""]);
+
+    assert_eq!(list.swap_remove(1), "bcd");
+    assert_eq!(list, str_list!["ef", ""]);
+
+    assert_eq!(list.swap_remove(1), "");
+    assert_eq!(list, str_list!["ef"]);
+
+    assert_eq!(list.swap_remove(0), "ef");
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_swap_remove_longer_last() {
+    let mut list = str_list!["", "ab", "c", "defgh"];
+
+    assert_eq!(list.swap_remove(0), "");
+    assert_eq!(list, str_list!["defgh", "ab", "c"]);
+
+    assert_eq!(list.swap_remove(1), "ab");
+    assert_eq!(list.as_bytes(), b"defgh\xffc\xff");
+}
+
+#[test]
+#[should_panic(expected = "swap_remove index out of bounds")]
+fn test_swap_remove_out_of_bounds() {
+    str_list!["a"].swap_remove(1);
+}
+
+#[test]
+fn test_start_element_write_macro() {
+    let mut list = str_list!["a"];
+    let mut writer = list.start_element();
+    let (number, letter) = (1, 'c');
+
+    write!(writer, "{number}-b").unwrap();
+    write!(writer.as_io(), "-{letter}").unwrap();
+
+    assert_eq!(writer.as_str(), "1-b-c");
+
+    writer.finish().unwrap();
+
+    assert_eq!(list, str_list!["a", "1-b-c"]);
+}
+
+#[test]
+fn test_start_element_split_char() {
+    let mut list = str_list!["a"];
+    let mut writer = list.start_element();
+    let bytes = "x😀ö".as_bytes();
+
+    for byte in bytes {
+        writer.as_io().write_all(&[*byte]).unwrap();
+    }
+
+    assert_eq!(writer.as_str(), "x😀ö");
+
+    writer.finish().unwrap();
+
+    assert_eq!(list, str_list!["a", "x😀ö"]);
+}
+
+#[test]
+fn test_start_element_invalid_byte() {
+    let mut list = str_list!["a"];
+    let mut writer = list.start_element();
+
+    assert_eq!(writer.as_io().write(b"bc\xffd").unwrap(), 2);
+    assert_eq!(
+        writer.as_io().write(b"\xffd").unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(
+        writer.as_io().write(b"\xe4\xff").unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(writer.as_str(), "bc");
+
+    drop(writer);
+
+    assert_eq!(list, str_list!["a", "bc"]);
+}
+
+#[test]
+fn test_start_element_incomplete_finish() {
+    let mut list = str_list!["a"];
+    let mut writer = list.start_element();
+
+    writer.as_io().write_all(b"b\xe4\xbd").unwrap();
+
+    assert_eq!(writer.as_str(), "b");
+    assert_eq!(writer.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(list.as_bytes(), b"a\xff");
+}
+
+#[test]
+fn test_start_element_incomplete_drop() {
+    let mut list = str_list!["a"];
+    let mut writer = list.start_element();
+
+    writer.as_io().write_all(b"b\xe4\xbd").unwrap();
+
+    drop(writer);
+
+    assert_eq!(list, str_list!["a", "b\u{fffd}"]);
+}
+
+#[test]
+fn test_start_element_incomplete_push_str() {
+    let mut list = str_list![];
+    let mut writer = list.start_element();
+
+    writer.as_io().write_all(b"\xf0\x9f").unwrap();
+    writer.push_str("c");
+    writer.finish().unwrap();
+
+    assert_eq!(list, str_list!["\u{fffd}c"]);
+}
+
+#[test]
+fn test_start_element_abort() {
+    let mut list = str_list!["a", "bc"];
+    let mut writer = list.start_element();
+
+    writer.push_str("def");
+    writer.as_io().write_all(b"\xe4").unwrap();
+    writer.abort();
+
+    assert_eq!(list.as_bytes(), b"a\xffbc\xff");
+}
+
+#[test]
+fn test_trim_in_place() {
+    let mut list = str_list![" a ", "b", "\tc d\n", "  ", "", "e  "];
+
+    list.trim_in_place();
+
+    assert_eq!(list.as_bytes(), b"a\xffb\xffc d\xff\xff\xffe\xff");
+}
+
+#[test]
+fn test_trim_in_place_empty() {
+    let mut list = str_list![];
+
+    list.trim_in_place();
+
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_trim_in_place_whitespace_only() {
+    let mut list = str_list![" ", "\t\n", "\u{3000}"];
+
+    list.trim_in_place();
+
+    assert_eq!(list.as_bytes(), b"\xff\xff\xff");
+}
+
+#[test]
+fn test_trim_in_place_unicode() {
+    let mut list = str_list!["\u{3000}α\u{3000}", "\u{a0}β γ\u{2003}"];
+
+    list.trim_in_place();
+
+    assert_eq!(list, str_list!["α", "β γ"]);
+}